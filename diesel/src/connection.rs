@@ -0,0 +1,79 @@
+use std::future::Future;
+
+use deadpool_sync::InteractError;
+
+use crate::{Connection, Error};
+
+/// Extension trait for [`Connection`] providing helpers for running
+/// [`diesel`] queries.
+pub trait ConnectionExt<C>
+where
+    C: diesel::Connection + 'static,
+{
+    /// Interacts with the underlying [`diesel::Connection`] just like
+    /// [`Connection::interact()`] but flattens the errors into a single
+    /// [`Error`].
+    ///
+    /// Errors returned by the closure are returned as [`Error::Query`] while
+    /// a panicking closure results in an [`Error::Interact`].
+    ///
+    /// [`Connection::interact()`]: deadpool_sync::SyncWrapper::interact
+    fn interact_diesel<F, R>(&self, f: F) -> impl Future<Output = Result<R, Error>> + Send
+    where
+        F: FnOnce(&mut C) -> Result<R, diesel::result::Error> + Send + 'static,
+        R: Send + 'static;
+
+    /// Runs the given closure inside a database transaction using
+    /// [`diesel::Connection::transaction()`].
+    ///
+    /// The transaction is committed if the closure returns [`Ok`] and rolled
+    /// back otherwise. Errors are returned just like
+    /// [`ConnectionExt::interact_diesel()`] does.
+    ///
+    /// ```rust,ignore
+    /// let user_id = conn
+    ///     .transaction(|conn| {
+    ///         diesel::insert_into(users::table)
+    ///             .values(users::name.eq("John"))
+    ///             .execute(conn)?;
+    ///         users::table.select(users::id).order(users::id.desc()).first::<i32>(conn)
+    ///     })
+    ///     .await?;
+    /// ```
+    fn transaction<F, R>(&self, f: F) -> impl Future<Output = Result<R, Error>> + Send
+    where
+        F: FnOnce(&mut C) -> Result<R, diesel::result::Error> + Send + 'static,
+        R: Send + 'static;
+}
+
+impl<C> ConnectionExt<C> for Connection<C>
+where
+    C: diesel::Connection + 'static,
+{
+    async fn interact_diesel<F, R>(&self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut C) -> Result<R, diesel::result::Error> + Send + 'static,
+        R: Send + 'static,
+    {
+        match self.interact(f).await {
+            Ok(result) => result.map_err(Error::Query),
+            Err(InteractError::Panic(p)) => {
+                let msg = p
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| p.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(Error::Interact(format!("Panic: {}", msg)))
+            }
+            Err(e) => Err(Error::Interact(e.to_string())),
+        }
+    }
+
+    async fn transaction<F, R>(&self, f: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut C) -> Result<R, diesel::result::Error> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.interact_diesel(move |conn| conn.transaction(f)).await
+    }
+}
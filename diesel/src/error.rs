@@ -13,6 +13,22 @@ pub enum Error {
     /// Failed to ping the database.
     Ping(diesel::result::Error),
 
+    /// Failed to execute one of the [`ManagerConfig::setup_statements`].
+    ///
+    /// [`ManagerConfig::setup_statements`]: crate::ManagerConfig::setup_statements
+    Setup(diesel::result::Error),
+
+    /// A query run via [`ConnectionExt::interact_diesel()`] failed.
+    ///
+    /// [`ConnectionExt::interact_diesel()`]: crate::ConnectionExt::interact_diesel
+    Query(diesel::result::Error),
+
+    /// The closure passed to [`ConnectionExt::interact_diesel()`] panicked
+    /// or was aborted.
+    ///
+    /// [`ConnectionExt::interact_diesel()`]: crate::ConnectionExt::interact_diesel
+    Interact(String),
+
     /// The transaction manager of a given
     /// connection is in a broken state. That usually
     /// means that it contains an open uncommited transaction
@@ -24,6 +40,9 @@ impl fmt::Display for Error {
         match self {
             Self::Connection(e) => write!(f, "Failed to establish connection: {}", e),
             Self::Ping(e) => write!(f, "Failed to ping database: {}", e),
+            Self::Setup(e) => write!(f, "Failed to set up connection: {}", e),
+            Self::Query(e) => write!(f, "Query failed: {}", e),
+            Self::Interact(msg) => write!(f, "Failed to interact with connection: {}", msg),
             Self::BrokenTransactionManger => write!(f, "Broken transaction manager"),
         }
     }
@@ -34,7 +53,9 @@ impl std::error::Error for Error {
         match self {
             Self::Connection(e) => Some(e),
             Self::Ping(e) => Some(e),
-            Self::BrokenTransactionManger => None,
+            Self::Setup(e) => Some(e),
+            Self::Query(e) => Some(e),
+            Self::Interact(_) | Self::BrokenTransactionManger => None,
         }
     }
 }
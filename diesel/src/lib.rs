@@ -21,6 +21,7 @@
 )]
 #![allow(clippy::uninlined_format_args)]
 
+mod connection;
 mod error;
 mod manager;
 
@@ -44,6 +45,7 @@ pub use deadpool_sync::reexports::*;
 pub use deadpool::managed::Pool;
 
 pub use self::{
+    connection::ConnectionExt,
     error::Error,
     manager::{Manager, ManagerConfig, RecycleCheckCallback, RecyclingMethod},
 };
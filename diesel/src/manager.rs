@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt, marker::PhantomData, sync::Arc};
+use std::{borrow::Cow, fmt, marker::PhantomData, sync::Arc, time::Duration};
 
 use deadpool::{
     managed::{self, Metrics, RecycleError, RecycleResult},
@@ -56,25 +56,67 @@ impl<C> Default for RecyclingMethod<C> {
 
 /// Configuration object for a Manager.
 ///
-/// This currently only makes it possible to specify which [`RecyclingMethod`]
-/// should be used when retrieving existing objects from the [`Pool`].
+/// This makes it possible to specify which [`RecyclingMethod`] should be
+/// used when retrieving existing objects from the [`Pool`] and how long
+/// connections may be reused.
 ///
 /// [`Pool`]: crate::Pool
-#[derive(Debug)]
 pub struct ManagerConfig<C> {
     /// Method of how a connection is recycled. See [RecyclingMethod].
     pub recycling_method: RecyclingMethod<C>,
+
+    /// Maximum lifetime of a connection. Connections which are older than
+    /// this are closed instead of being recycled. This is useful for
+    /// databases closing idle connections on their own, e.g. MySQL's
+    /// `wait_timeout`.
+    ///
+    /// This is similar to [`PoolConfig::max_lifetime`] which is checked by
+    /// the [`Pool`] itself before running the `pre_recycle` hooks and
+    /// silently replaces the connection. This one is checked by
+    /// [`Manager::recycle()`] instead so exceeding it counts as a failed
+    /// recycle and it isn't checked if recycling is skipped via
+    /// [`GetOptions::skip_recycle`]. Prefer [`PoolConfig::max_lifetime`]
+    /// unless the lifetime should be enforced by the [`Manager`] itself,
+    /// e.g. when it is shared by multiple pools.
+    ///
+    /// Default: No maximum lifetime
+    ///
+    /// [`GetOptions::skip_recycle`]: managed::GetOptions::skip_recycle
+    /// [`Manager::recycle()`]: managed::Manager::recycle
+    /// [`Pool`]: managed::Pool
+    /// [`PoolConfig::max_lifetime`]: managed::PoolConfig::max_lifetime
+    pub max_lifetime: Option<Duration>,
+
+    /// SQL statements which are executed after establishing a new
+    /// connection, e.g. `SET time_zone = 'UTC'`.
+    ///
+    /// Default: No statements
+    pub setup_statements: Vec<Cow<'static, str>>,
 }
 
 impl<C> Default for ManagerConfig<C> {
     fn default() -> Self {
         Self {
             recycling_method: Default::default(),
+            max_lifetime: None,
+            setup_statements: Vec::new(),
         }
     }
 }
 
-impl<C: fmt::Debug> fmt::Debug for RecyclingMethod<C> {
+// Implemented manually to avoid unnecessary trait bound on `C` type parameter.
+impl<C> fmt::Debug for ManagerConfig<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ManagerConfig")
+            .field("recycling_method", &self.recycling_method)
+            .field("max_lifetime", &self.max_lifetime)
+            .field("setup_statements", &self.setup_statements)
+            .finish()
+    }
+}
+
+// Implemented manually to avoid unnecessary trait bound on `C` type parameter.
+impl<C> fmt::Debug for RecyclingMethod<C> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Fast => write!(f, "Fast"),
@@ -140,13 +182,25 @@ where
 
     async fn create(&self) -> Result<Self::Type, Self::Error> {
         let database_url = self.database_url.clone();
-        SyncWrapper::new(self.runtime, move || {
-            C::establish(&database_url).map_err(Into::into)
+        let config = Arc::clone(&self.manager_config);
+        SyncWrapper::new(self.runtime, move || -> Result<C, Error> {
+            let mut conn = C::establish(&database_url)?;
+            for statement in &config.setup_statements {
+                let _ = diesel::sql_query(statement.as_ref())
+                    .execute(&mut conn)
+                    .map_err(Error::Setup)?;
+            }
+            Ok(conn)
         })
         .await
     }
 
-    async fn recycle(&self, obj: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
+    async fn recycle(&self, obj: &mut Self::Type, metrics: &Metrics) -> RecycleResult<Self::Error> {
+        if let Some(max_lifetime) = self.manager_config.max_lifetime {
+            if metrics.age() > max_lifetime {
+                return Err(RecycleError::message("Maximum lifetime exceeded"));
+            }
+        }
         if obj.is_mutex_poisoned() {
             return Err(RecycleError::message(
                 "Mutex is poisoned. Connection is considered unusable.",
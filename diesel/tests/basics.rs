@@ -88,3 +88,136 @@ async fn hooks() {
         .build()
         .unwrap();
 }
+
+#[tokio::test]
+async fn max_lifetime() {
+    use std::time::Duration;
+
+    use deadpool_diesel::{sqlite::Object, ManagerConfig};
+
+    let manager = Manager::from_config(
+        ":memory:",
+        Runtime::Tokio1,
+        ManagerConfig {
+            max_lifetime: Some(Duration::from_millis(10)),
+            ..Default::default()
+        },
+    );
+    let pool = Pool::builder(manager).max_size(1).build().unwrap();
+    let conn = pool.get().await.unwrap();
+    let created = Object::metrics(&conn).created;
+    drop(conn);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let conn = pool.get().await.unwrap();
+    assert_ne!(Object::metrics(&conn).created, created);
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn interact_diesel() {
+    use diesel::prelude::*;
+    use diesel::select;
+    use diesel::sql_types::Text;
+
+    use deadpool_diesel::{ConnectionExt, Error};
+
+    let pool = create_pool(1);
+    let conn = pool.get().await.unwrap();
+    let value = conn
+        .interact_diesel(|conn| select("foo".into_sql::<Text>()).get_result::<String>(conn))
+        .await
+        .unwrap();
+    assert_eq!(value, "foo");
+    let result = conn
+        .interact_diesel(|conn| diesel::sql_query("SELECT * FROM missing").execute(conn))
+        .await;
+    assert!(matches!(result, Err(Error::Query(_))));
+    let result = conn
+        .interact_diesel(|_| -> QueryResult<()> { panic!("boom") })
+        .await;
+    assert!(matches!(result, Err(Error::Interact(msg)) if msg == "Panic: boom"));
+}
+
+#[tokio::test]
+async fn setup_statements() {
+    use diesel::prelude::*;
+    use diesel::{dsl::sql, select, sql_types::Integer};
+
+    use deadpool_diesel::ManagerConfig;
+
+    let manager = Manager::from_config(
+        ":memory:",
+        Runtime::Tokio1,
+        ManagerConfig {
+            setup_statements: vec!["PRAGMA user_version = 42".into()],
+            ..Default::default()
+        },
+    );
+    let pool = Pool::builder(manager).max_size(1).build().unwrap();
+    let conn = pool.get().await.unwrap();
+    let user_version = conn
+        .interact(|conn| {
+            select(sql::<Integer>(
+                "(SELECT user_version FROM pragma_user_version())",
+            ))
+            .get_result::<i32>(conn)
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(user_version, 42);
+}
+
+#[tokio::test]
+async fn transaction() {
+    use diesel::prelude::*;
+    use diesel::{dsl::sql, select, sql_query, sql_types::Integer};
+
+    use deadpool_diesel::{ConnectionExt, Error};
+
+    let count = |conn: &mut SqliteConnection| {
+        select(sql::<Integer>("(SELECT COUNT(*) FROM items)")).get_result::<i32>(conn)
+    };
+
+    let pool = create_pool(1);
+    let conn = pool.get().await.unwrap();
+    conn.interact_diesel(|conn| sql_query("CREATE TABLE items (id INTEGER)").execute(conn))
+        .await
+        .unwrap();
+    let result = conn
+        .transaction(move |conn| {
+            sql_query("INSERT INTO items VALUES (1)").execute(conn)?;
+            count(conn)
+        })
+        .await;
+    assert_eq!(result.unwrap(), 1);
+    let result = conn
+        .transaction(|conn| {
+            sql_query("INSERT INTO items VALUES (2)").execute(conn)?;
+            Err::<(), _>(diesel::result::Error::RollbackTransaction)
+        })
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::Query(diesel::result::Error::RollbackTransaction))
+    ));
+    assert_eq!(conn.interact_diesel(count).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn pool_debug() {
+    // `diesel` connections don't implement `Debug`. This must compile
+    // nevertheless so applications can derive `Debug` for their state.
+    #[derive(Debug)]
+    struct AppState {
+        pool: Pool,
+    }
+
+    let state = AppState {
+        pool: create_pool(1),
+    };
+    let conn = state.pool.get().await.unwrap();
+    assert!(format!("{:?}", conn).starts_with("Object"));
+    drop(conn);
+    assert!(format!("{:?}", state).starts_with("AppState"));
+}
@@ -1,6 +1,7 @@
 use deadpool_diesel::postgres::{BuildError, Manager, Pool};
 
-use deadpool_diesel::Runtime;
+use deadpool_diesel::{ConnectionExt, Runtime};
+use diesel::{dsl::sql, sql_types::Text, RunQueryDsl};
 
 use std::env;
 use thiserror::Error;
@@ -33,4 +34,21 @@ pub fn set_up_pool() -> Result<Pool, PoolError> {
     Ok(pool)
 }
 
+#[derive(Error, Debug)]
+pub enum QueryError {
+    #[error("unable to get connection: {0}")]
+    Pool(#[from] deadpool_diesel::postgres::PoolError),
+
+    #[error("query failed: {0}")]
+    Diesel(#[from] deadpool_diesel::Error),
+}
+
+pub async fn database_version(pool: &Pool) -> Result<String, QueryError> {
+    let conn = pool.get().await?;
+    let version = conn
+        .interact_diesel(|conn| diesel::select(sql::<Text>("version()")).get_result(conn))
+        .await?;
+    Ok(version)
+}
+
 pub fn main() {}
@@ -1,7 +1,7 @@
 use std::env;
 
 use actix_web::{error, get, middleware, web, App, Error, HttpResponse, HttpServer};
-use deadpool_redis::{redis::cmd, Config as RedisConfig, Connection, Pool, PoolError, Runtime};
+use deadpool_redis::{Config as RedisConfig, Pool, PoolExt, Runtime};
 
 fn redis_uri() -> String {
     match env::var("REDIS_URL") {
@@ -10,16 +10,10 @@ fn redis_uri() -> String {
     }
 }
 
-async fn redis_ping(pool: &Pool) -> Result<String, PoolError> {
-    let mut conn: Connection = pool.get().await?;
-    let pong: String = cmd("PING").query_async(&mut conn).await?;
-
-    Ok(pong)
-}
-
 #[get("/")]
 async fn index(redis_pool: web::Data<Pool>) -> Result<HttpResponse, Error> {
-    let pong = redis_ping(&redis_pool)
+    let pong = redis_pool
+        .ping()
         .await
         .map_err(|pool_error| error::ErrorNotAcceptable(format!("{pool_error}")))?;
 
@@ -0,0 +1,123 @@
+//! This module extends the library to support pooling [`lapin::Channel`]s.
+//!
+//! AMQP connections are meant to be long lived and shared by many lightweight
+//! channels. The [`Manager`] of this module keeps a single
+//! [`lapin::Connection`] and creates the pooled channels using it.
+use std::sync::Arc;
+
+use deadpool::managed;
+use lapin::{ConnectionProperties, Error};
+use tokio::sync::Mutex;
+
+use crate::ConfigError;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!(
+    "lapin_channel",
+    Manager,
+    managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+/// Type alias for ['Object']
+pub type Channel = managed::Object<Manager>;
+
+type RecycleResult = managed::RecycleResult<Error>;
+type RecycleError = managed::RecycleError<Error>;
+
+/// [`Manager`] for creating and recycling [`lapin::Channel`]s which all
+/// share a single [`lapin::Connection`].
+///
+/// The [`lapin::Connection`] is established by a [`crate::Manager`] when the
+/// first channel is created. If creating a channel fails because the
+/// connection was lost, a new connection is established and channel creation
+/// is retried once. Reconnects are serialized so concurrent failures only
+/// establish a single new connection and the lost one is closed. Channels of
+/// the lost connection fail to recycle and are removed from the [`Pool`].
+///
+/// [`Manager`]: managed::Manager
+pub struct Manager {
+    manager: crate::Manager,
+    connection: Mutex<Option<Arc<lapin::Connection>>>,
+}
+
+impl std::fmt::Debug for Manager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Manager")
+            .field("manager", &self.manager)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] using the given AMQP address and
+    /// [`lapin::ConnectionProperties`].
+    #[must_use]
+    pub fn new<S: Into<String>>(addr: S, connection_properties: ConnectionProperties) -> Self {
+        Self {
+            manager: crate::Manager::new(addr, connection_properties),
+            connection: Mutex::new(None),
+        }
+    }
+
+    /// Sets how many times establishing the shared connection is retried
+    /// before giving up. See [`crate::Manager::with_connect_retries()`].
+    #[must_use]
+    pub fn with_connect_retries(mut self, connect_retries: usize) -> Self {
+        self.manager = self.manager.with_connect_retries(connect_retries);
+        self
+    }
+
+    /// Sets the [`Runtime`] which is used for waiting between two attempts of
+    /// establishing the shared connection. See
+    /// [`crate::Manager::with_runtime()`].
+    #[must_use]
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.manager = self.manager.with_runtime(runtime);
+        self
+    }
+
+    /// Returns the shared [`lapin::Connection`] establishing a new one if
+    /// there is none or the current one is no longer connected.
+    ///
+    /// The lock is held while connecting, so tasks waiting for it find the
+    /// new connection instead of establishing another one.
+    async fn connection(&self) -> Result<Arc<lapin::Connection>, Error> {
+        let mut current = self.connection.lock().await;
+        if let Some(conn) = current.as_ref().filter(|c| c.status().connected()) {
+            return Ok(conn.clone());
+        }
+        let conn = Arc::new(managed::Manager::create(&self.manager).await?);
+        if let Some(old) = current.replace(conn.clone()) {
+            // The old connection is already broken, so failing to close it
+            // cleanly is expected.
+            let _ = old.close(200, "reconnecting").await;
+        }
+        Ok(conn)
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = lapin::Channel;
+    type Error = Error;
+
+    async fn create(&self) -> Result<lapin::Channel, Error> {
+        let conn = self.connection().await?;
+        match conn.create_channel().await {
+            Err(_) if !conn.status().connected() => self.connection().await?.create_channel().await,
+            result => result,
+        }
+    }
+
+    async fn recycle(&self, channel: &mut lapin::Channel, _: &Metrics) -> RecycleResult {
+        if channel.status().connected() {
+            Ok(())
+        } else {
+            Err(RecycleError::message(format!(
+                "lapin channel is in state: {:?}",
+                channel.status().state()
+            )))
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::convert::Infallible;
 
-use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+use crate::{
+    ChannelManager, ChannelPool, ChannelPoolBuilder, CreatePoolError, Manager, Pool, PoolBuilder,
+    PoolConfig, Runtime,
+};
 
 /// Configuration object.
 ///
@@ -13,6 +16,8 @@ use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
 /// AMQP__POOL__MAX_SIZE=16
 /// AMQP__POOL__TIMEOUTS__WAIT__SECS=2
 /// AMQP__POOL__TIMEOUTS__WAIT__NANOS=0
+/// AMQP__CONNECTION__HEARTBEAT=10
+/// AMQP__CONNECTION__CONNECTION_NAME=my-service
 /// ```
 /// ```rust
 /// #[derive(serde::Deserialize)]
@@ -41,6 +46,19 @@ pub struct Config {
     /// Connection properties.
     #[cfg_attr(feature = "serde", serde(skip))]
     pub connection_properties: lapin::ConnectionProperties,
+
+    /// Connection tuning which, unlike [`Config::connection_properties`],
+    /// can be read via `serde`.
+    pub connection: Option<ConnectionConfig>,
+
+    /// Number of times establishing a new connection is retried before
+    /// giving up. The attempts are delayed using the [`Runtime`] passed to
+    /// [`Config::builder()`]. Without a [`Runtime`] they are made
+    /// immediately, see [`Manager::with_connect_retries()`].
+    ///
+    /// Default: `0` (no retries)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub connect_retries: usize,
 }
 
 pub(crate) struct ConnProps<'a>(pub(crate) &'a lapin::ConnectionProperties);
@@ -62,6 +80,8 @@ impl std::fmt::Debug for Config {
                 "connection_properties",
                 &ConnProps(&self.connection_properties),
             )
+            .field("connection", &self.connection)
+            .field("connect_retries", &self.connect_retries)
             .finish()
     }
 }
@@ -80,29 +100,79 @@ impl Config {
 
     /// Creates a new [`PoolBuilder`] using this [`Config`].
     pub fn builder(&self, runtime: Option<Runtime>) -> PoolBuilder {
-        let url = self.get_url().to_string();
+        let url = self.get_connection_url();
         let pool_config = self.get_pool_config();
+        let conn_props = self.get_connection_properties(runtime);
+
+        let mut manager = Manager::new(url, conn_props).with_connect_retries(self.connect_retries);
+        if let Some(runtime) = runtime {
+            manager = manager.with_runtime(runtime);
+        }
+
+        let mut builder = Pool::builder(manager).config(pool_config);
+
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime)
+        }
+
+        builder
+    }
 
-        let conn_props = self.connection_properties.clone();
-        let conn_props = match runtime {
+    /// Creates a new [`ChannelPool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_channel_pool(
+        &self,
+        runtime: Option<Runtime>,
+    ) -> Result<ChannelPool, CreatePoolError> {
+        self.channel_builder(runtime)
+            .build()
+            .map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`ChannelPoolBuilder`] using this [`Config`].
+    pub fn channel_builder(&self, runtime: Option<Runtime>) -> ChannelPoolBuilder {
+        let url = self.get_connection_url();
+        let pool_config = self.get_pool_config();
+        let conn_props = self.get_connection_properties(runtime);
+
+        let mut manager =
+            ChannelManager::new(url, conn_props).with_connect_retries(self.connect_retries);
+        if let Some(runtime) = runtime {
+            manager = manager.with_runtime(runtime);
+        }
+
+        let mut builder = ChannelPool::builder(manager).config(pool_config);
+
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime)
+        }
+
+        builder
+    }
+
+    fn get_connection_properties(&self, runtime: Option<Runtime>) -> lapin::ConnectionProperties {
+        let mut conn_props = self.connection_properties.clone();
+        if let Some(name) = self
+            .connection
+            .as_ref()
+            .and_then(|c| c.connection_name.clone())
+        {
+            conn_props = conn_props.with_connection_name(name.into());
+        }
+        match runtime {
             None => conn_props,
             #[cfg(feature = "rt_tokio_1")]
-            Some(Runtime::Tokio1) => {
+            Some(Runtime::Tokio1 | Runtime::Tokio1CurrentThread) => {
                 conn_props.with_executor(tokio_executor_trait::Tokio::current())
             }
             #[cfg(feature = "rt_async-std_1")]
             Some(Runtime::AsyncStd1) => conn_props.with_executor(async_executor_trait::AsyncStd),
             #[allow(unreachable_patterns)]
             _ => unreachable!(),
-        };
-
-        let mut builder = Pool::builder(Manager::new(url, conn_props)).config(pool_config);
-
-        if let Some(runtime) = runtime {
-            builder = builder.runtime(runtime)
         }
-
-        builder
     }
 
     /// Returns URL which can be used to connect to the database.
@@ -110,6 +180,44 @@ impl Config {
         self.url.as_deref().unwrap_or("amqp://127.0.0.1:5672/%2f")
     }
 
+    /// Returns the URL returned by [`Config::get_url()`] with the tuning
+    /// options of [`Config::connection`] set as query parameters. Query
+    /// parameters of the same name which are already present are replaced.
+    #[must_use]
+    pub fn get_connection_url(&self) -> String {
+        let url = self.get_url();
+        let Some(connection) = &self.connection else {
+            return url.to_string();
+        };
+        let params = [
+            ("heartbeat", connection.heartbeat.map(u32::from)),
+            ("channel_max", connection.channel_max.map(u32::from)),
+            ("frame_max", connection.frame_max),
+        ];
+        let (base, query) = url.split_once('?').unwrap_or((url, ""));
+        let mut pairs = query
+            .split('&')
+            .filter(|pair| {
+                let name = pair.split_once('=').map_or(*pair, |(name, _)| name);
+                !pair.is_empty()
+                    && !params
+                        .iter()
+                        .any(|(param, value)| value.is_some() && *param == name)
+            })
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        for (name, value) in params {
+            if let Some(value) = value {
+                pairs.push(format!("{}={}", name, value));
+            }
+        }
+        if pairs.is_empty() {
+            base.to_string()
+        } else {
+            format!("{}?{}", base, pairs.join("&"))
+        }
+    }
+
     /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
     /// a [`deadpool::managed::Pool`] instance.
     #[must_use]
@@ -118,6 +226,37 @@ impl Config {
     }
 }
 
+/// Tuning options of a [`lapin::Connection`] which can be read via `serde`.
+///
+/// The `heartbeat`, `channel_max` and `frame_max` options are passed to
+/// [`lapin`] as query parameters of the [`Config::url`] and replace the
+/// ones already present in it (see [`Config::get_connection_url()`]). They are negotiated with the server when
+/// establishing a new connection.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ConnectionConfig {
+    /// Heartbeat interval in seconds. `0` disables heartbeats.
+    ///
+    /// Default: The value suggested by the server
+    pub heartbeat: Option<u16>,
+
+    /// Maximum number of channels per connection.
+    ///
+    /// Default: The value suggested by the server
+    pub channel_max: Option<u16>,
+
+    /// Maximum size of a frame in bytes.
+    ///
+    /// Default: The value suggested by the server
+    pub frame_max: Option<u32>,
+
+    /// Name of the connection which is shown in the management UI of the
+    /// server.
+    ///
+    /// Default: No name
+    pub connection_name: Option<String>,
+}
+
 /// This error is returned if there is something wrong with the lapin configuration.
 ///
 /// This is just a type alias to [`Infallible`] at the moment as there
@@ -21,14 +21,20 @@
 )]
 #![allow(clippy::uninlined_format_args)]
 
+pub mod channel;
 mod config;
 
+use std::time::Duration;
+
 use deadpool::managed;
 use lapin::{ConnectionProperties, Error};
 
 pub use lapin;
 
-pub use self::config::{Config, ConfigError};
+pub use self::channel::{
+    Channel, Manager as ChannelManager, Pool as ChannelPool, PoolBuilder as ChannelPoolBuilder,
+};
+pub use self::config::{Config, ConfigError, ConnectionConfig};
 
 pub use deadpool::managed::reexports::*;
 deadpool::managed_reexports!(
@@ -45,12 +51,22 @@ pub type Connection = managed::Object<Manager>;
 type RecycleResult = managed::RecycleResult<Error>;
 type RecycleError = managed::RecycleError<Error>;
 
+/// Delay between two attempts of establishing a [`lapin::Connection`].
+const CONNECT_RETRY_DELAY: Duration = Duration::from_millis(100);
+
 /// [`Manager`] for creating and recycling [`lapin::Connection`].
 ///
+/// Establishing a new connection is retried up to
+/// [`Manager::with_connect_retries()`] times. Recycling a connection only
+/// validates that it is still connected. Otherwise it is removed from the
+/// [`Pool`] and a new connection is created on demand.
+///
 /// [`Manager`]: managed::Manager
 pub struct Manager {
     addr: String,
     connection_properties: ConnectionProperties,
+    connect_retries: usize,
+    runtime: Option<Runtime>,
 }
 
 impl std::fmt::Debug for Manager {
@@ -61,6 +77,8 @@ impl std::fmt::Debug for Manager {
                 "connection_properties",
                 &config::ConnProps(&self.connection_properties),
             )
+            .field("connect_retries", &self.connect_retries)
+            .field("runtime", &self.runtime)
             .finish()
     }
 }
@@ -73,8 +91,34 @@ impl Manager {
         Self {
             addr: addr.into(),
             connection_properties,
+            connect_retries: 0,
+            runtime: None,
         }
     }
+
+    /// Sets how many times establishing a new connection is retried before
+    /// giving up.
+    ///
+    /// The attempts are delayed by 100 milliseconds using the [`Runtime`]
+    /// set via [`Manager::with_runtime()`]. **Without a [`Runtime`] all
+    /// retries are made immediately** which is unlikely to help with a
+    /// server which isn't reachable yet.
+    ///
+    /// Default: `0` (no retries)
+    #[must_use]
+    pub fn with_connect_retries(mut self, connect_retries: usize) -> Self {
+        self.connect_retries = connect_retries;
+        self
+    }
+
+    /// Sets the [`Runtime`] which is used for waiting between two attempts of
+    /// establishing a new connection. Without a [`Runtime`] the attempts are
+    /// made without any delay.
+    #[must_use]
+    pub fn with_runtime(mut self, runtime: Runtime) -> Self {
+        self.runtime = Some(runtime);
+        self
+    }
 }
 
 impl managed::Manager for Manager {
@@ -82,10 +126,21 @@ impl managed::Manager for Manager {
     type Error = Error;
 
     async fn create(&self) -> Result<lapin::Connection, Error> {
-        let conn =
-            lapin::Connection::connect(self.addr.as_str(), self.connection_properties.clone())
-                .await?;
-        Ok(conn)
+        let mut retries = 0;
+        loop {
+            match lapin::Connection::connect(self.addr.as_str(), self.connection_properties.clone())
+                .await
+            {
+                Ok(conn) => return Ok(conn),
+                Err(e) if retries >= self.connect_retries => return Err(e),
+                Err(_) => {
+                    retries += 1;
+                    if let Some(runtime) = self.runtime {
+                        runtime.sleep(CONNECT_RETRY_DELAY).await;
+                    }
+                }
+            }
+        }
     }
 
     async fn recycle(&self, conn: &mut lapin::Connection, _: &Metrics) -> RecycleResult {
@@ -0,0 +1,57 @@
+use std::time::{Duration, Instant};
+
+use deadpool_lapin::{lapin::ConnectionProperties, Manager, Pool, PoolError};
+
+/// Nothing listens on this port so connecting fails right away.
+const UNREACHABLE_URL: &str = "amqp://127.0.0.1:1/%2f";
+
+const CONNECT_RETRIES: usize = 3;
+
+#[tokio::test]
+async fn connect_retries_without_runtime() {
+    let manager = Manager::new(UNREACHABLE_URL, ConnectionProperties::default())
+        .with_connect_retries(CONNECT_RETRIES);
+    let pool = Pool::builder(manager).max_size(1).build().unwrap();
+    let start = Instant::now();
+    assert!(matches!(pool.get().await, Err(PoolError::Backend(_))));
+    // Without a runtime the retries are not delayed.
+    assert!(start.elapsed() < Duration::from_millis(100));
+}
+
+#[cfg(feature = "rt_tokio_1")]
+#[tokio::test]
+async fn connect_retries_with_runtime() {
+    use deadpool_lapin::Runtime;
+
+    let manager = Manager::new(UNREACHABLE_URL, ConnectionProperties::default())
+        .with_connect_retries(CONNECT_RETRIES)
+        .with_runtime(Runtime::Tokio1);
+    let pool = Pool::builder(manager)
+        .max_size(1)
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    let start = Instant::now();
+    assert!(matches!(pool.get().await, Err(PoolError::Backend(_))));
+    // Each retry is delayed by 100 milliseconds.
+    assert!(start.elapsed() >= Duration::from_millis(300));
+}
+
+#[test]
+fn connection_url_replaces_params() {
+    use deadpool_lapin::{Config, ConnectionConfig};
+
+    let cfg = Config {
+        url: Some("amqp://127.0.0.1:5672/%2f?heartbeat=10&locale=en_US".into()),
+        connection: Some(ConnectionConfig {
+            heartbeat: Some(30),
+            frame_max: Some(8192),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    assert_eq!(
+        cfg.get_connection_url(),
+        "amqp://127.0.0.1:5672/%2f?locale=en_US&heartbeat=30&frame_max=8192"
+    );
+}
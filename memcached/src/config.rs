@@ -0,0 +1,141 @@
+use std::convert::Infallible;
+
+use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
+
+/// Configuration object.
+///
+/// # Example (from environment)
+///
+/// By enabling the `serde` feature you can read the configuration using the
+/// [`config`](https://crates.io/crates/config) crate as following:
+/// ```env
+/// MEMCACHED__URL=127.0.0.1:11211
+/// MEMCACHED__MANAGER__RECYCLING_METHOD=Fast
+/// MEMCACHED__POOL__MAX_SIZE=16
+/// MEMCACHED__POOL__TIMEOUTS__WAIT__SECS=2
+/// MEMCACHED__POOL__TIMEOUTS__WAIT__NANOS=0
+/// ```
+/// ```rust
+/// #[derive(serde::Deserialize)]
+/// struct Config {
+///     memcached: deadpool_memcached::Config,
+/// }
+///
+/// impl Config {
+///     pub fn from_env() -> Result<Self, config::ConfigError> {
+///         config::Config::builder()
+///             .add_source(config::Environment::default().separator("__"))
+///             .build()?
+///             .try_deserialize()
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Config {
+    /// URL of the memcached server (like `127.0.0.1:11211` or
+    /// `tcp://127.0.0.1:11211`).
+    pub url: Option<String>,
+
+    /// [`Manager`] configuration.
+    pub manager: Option<ManagerConfig>,
+
+    /// [`Pool`] configuration.
+    pub pool: Option<PoolConfig>,
+}
+
+impl Config {
+    /// Creates a new [`Config`] from the given memcached URL (like
+    /// `127.0.0.1:11211`).
+    #[must_use]
+    pub fn from_url<T: Into<String>>(url: T) -> Self {
+        Self {
+            url: Some(url.into()),
+            manager: None,
+            pool: None,
+        }
+    }
+
+    /// Creates a new [`Pool`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`CreatePoolError`] for details.
+    pub fn create_pool(&self, runtime: Option<Runtime>) -> Result<Pool, CreatePoolError> {
+        let mut builder = self.builder().map_err(CreatePoolError::Config)?;
+        if let Some(runtime) = runtime {
+            builder = builder.runtime(runtime);
+        }
+        builder.build().map_err(CreatePoolError::Build)
+    }
+
+    /// Creates a new [`PoolBuilder`] using this [`Config`].
+    ///
+    /// # Errors
+    ///
+    /// See [`ConfigError`] for details.
+    pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
+        let manager = Manager::from_config(self.get_url(), self.get_manager_config());
+        Ok(Pool::builder(manager).config(self.get_pool_config()))
+    }
+
+    /// Returns the URL of the memcached server.
+    #[must_use]
+    pub fn get_url(&self) -> &str {
+        self.url.as_deref().unwrap_or("127.0.0.1:11211")
+    }
+
+    /// Returns the [`ManagerConfig`] which can be used to construct a
+    /// [`Manager`] instance.
+    #[must_use]
+    pub fn get_manager_config(&self) -> ManagerConfig {
+        self.manager.clone().unwrap_or_default()
+    }
+
+    /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
+    /// a [`deadpool::managed::Pool`] instance.
+    #[must_use]
+    pub fn get_pool_config(&self) -> PoolConfig {
+        self.pool.unwrap_or_default()
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This currently only makes it possible to specify which
+/// [`RecyclingMethod`] should be used when retrieving existing connections
+/// from the [`Pool`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recycling_method: RecyclingMethod,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Verified`] which makes sure the connection still works.
+///
+/// [`Verified`]: RecyclingMethod::Verified
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecyclingMethod {
+    /// Return the connection without sending any command to the server.
+    ///
+    /// This saves a round trip when retrieving a connection but broken
+    /// connections are only detected once they are used.
+    Fast,
+
+    /// Run `version` before returning the connection which makes sure the
+    /// connection still works.
+    #[default]
+    Verified,
+}
+
+/// This error is returned if there is something wrong with the memcached
+/// configuration.
+///
+/// This is just a type alias to [`Infallible`] at the moment as there
+/// is no validation happening at the configuration phase.
+pub type ConfigError = Infallible;
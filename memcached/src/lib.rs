@@ -1,33 +1,48 @@
-//! # Deadpool for Memcache
-//!
-//! Deadpool is a dead simple async pool for connections and objects of any type.
-//!
-//! This crate implements a [`deadpool`](https://crates.io/crates/deadpool) manager for
-//! [`async-memcached`](https://crates.io/crates/async-memcached).  We specifically force users to
-//! connect via TCP as there is no existing mechanism to parameterize how to deal with different
-//! unerlying connection types at the moment.
+#![doc = include_str!("../README.md")]
 #![deny(warnings, missing_docs)]
-use std::convert::Infallible;
+
+mod config;
 
 use async_memcached::{Client, Error};
 
-/// Type alias for using [`deadpool::managed::RecycleResult`] with [`redis`].
-type RecycleResult = deadpool::managed::RecycleResult<Error>;
+pub use async_memcached;
 
-type ConfigError = Infallible;
+pub use self::config::{Config, ConfigError, ManagerConfig, RecyclingMethod};
+
+/// Type alias for using [`deadpool::managed::RecycleResult`] with [`async_memcached`].
+type RecycleResult = deadpool::managed::RecycleResult<Error>;
 
 pub use deadpool::managed::reexports::*;
-deadpool::managed_reexports!("memcached", Manager, Client, Error, ConfigError);
+deadpool::managed_reexports!(
+    "memcached",
+    Manager,
+    deadpool::managed::Object<Manager>,
+    Error,
+    ConfigError
+);
+
+/// Type alias for [`Object`]
+pub type Connection = Object;
 
 /// The manager for creating and recyling memcache connections
+#[derive(Debug)]
 pub struct Manager {
     addr: String,
+    config: ManagerConfig,
 }
 
 impl Manager {
     /// Create a new manager for the given address.
     pub fn new(addr: String) -> Self {
-        Self { addr }
+        Self::from_config(addr, ManagerConfig::default())
+    }
+
+    /// Create a new manager for the given address and [`ManagerConfig`].
+    pub fn from_config(addr: impl Into<String>, config: ManagerConfig) -> Self {
+        Self {
+            addr: addr.into(),
+            config,
+        }
     }
 }
 
@@ -40,6 +55,9 @@ impl deadpool::managed::Manager for Manager {
     }
 
     async fn recycle(&self, conn: &mut Client, _: &Metrics) -> RecycleResult {
+        if self.config.recycling_method == RecyclingMethod::Fast {
+            return Ok(());
+        }
         match conn.version().await {
             Ok(_) => Ok(()),
             Err(e) => Err(e.into()),
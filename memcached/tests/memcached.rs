@@ -0,0 +1,47 @@
+#![cfg(feature = "serde")]
+
+use deadpool_memcached::{ManagerConfig, RecyclingMethod};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct Config {
+    #[serde(default)]
+    memcached: deadpool_memcached::Config,
+}
+
+impl Config {
+    pub fn from_env() -> Self {
+        config::Config::builder()
+            .add_source(config::Environment::default().separator("__"))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap()
+    }
+}
+
+fn create_pool() -> deadpool_memcached::Pool {
+    let cfg = Config::from_env();
+    cfg.memcached.create_pool(None).unwrap()
+}
+
+#[tokio::test]
+async fn test_version() {
+    let pool = create_pool();
+    let mut conn = pool.get().await.unwrap();
+    let version = conn.version().await.unwrap();
+    assert!(!version.is_empty());
+}
+
+#[tokio::test]
+async fn test_recycling_method_fast() {
+    let mut cfg = Config::from_env();
+    cfg.memcached.manager = Some(ManagerConfig {
+        recycling_method: RecyclingMethod::Fast,
+    });
+    let pool = cfg.memcached.create_pool(None).unwrap();
+    drop(pool.get().await.unwrap());
+    let mut conn = pool.get().await.unwrap();
+    assert!(conn.version().await.is_ok());
+    assert_eq!(pool.status().size, 1);
+}
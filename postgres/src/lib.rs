@@ -26,27 +26,33 @@ mod generic_client;
 use std::{
     borrow::Cow,
     collections::HashMap,
+    error::Error as StdError,
     fmt,
     future::Future,
+    io,
     ops::{Deref, DerefMut},
     pin::Pin,
     sync::{
-        atomic::{AtomicUsize, Ordering},
-        Arc, Mutex, RwLock, Weak,
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex, OnceLock, RwLock, Weak,
     },
 };
 
 use deadpool::managed;
+use futures_util::future::try_join_all;
 #[cfg(not(target_arch = "wasm32"))]
 use tokio::spawn;
 use tokio::task::JoinHandle;
 use tokio_postgres::{
-    types::Type, Client as PgClient, Config as PgConfig, Error, IsolationLevel, Statement,
+    error::SqlState,
+    types::{ToSql, Type},
+    Client as PgClient, Config as PgConfig, Error, IsolationLevel, Row, Statement,
     Transaction as PgTransaction, TransactionBuilder as PgTransactionBuilder,
 };
 
 #[cfg(not(target_arch = "wasm32"))]
 use tokio_postgres::{
+    config::Host,
     tls::{MakeTlsConnect, TlsConnect},
     Socket,
 };
@@ -74,6 +80,128 @@ type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 /// Type alias for [`Object`]
 pub type Client = Object;
 
+/// Extension trait for [`Pool`] providing additional ways of retrieving a
+/// [`Client`].
+pub trait PoolExt {
+    /// Retrieves a [`Client`] for operations relying on session state, e.g.
+    /// advisory locks, temporary tables or `SET` outside of a transaction.
+    ///
+    /// The [`Client`] is recycled as usual before being handed out. Once it
+    /// is dropped it is returned to the [`Pool`] and recycled using
+    /// [`RecyclingMethod::Clean`] the next time it is retrieved, no matter
+    /// which [`RecyclingMethod`] is configured. The session state is
+    /// therefore never inherited by another user of the [`Pool`] while the
+    /// connection itself is reused. If resetting the session fails the
+    /// connection is closed and removed from the [`Pool`].
+    ///
+    /// **Important:** Prepared statements created via SQL `PREPARE` and
+    /// anything else not covered by [`RecyclingMethod::Clean`] are not
+    /// reset.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    fn dedicated(&self) -> impl Future<Output = Result<Client, PoolError>> + Send;
+}
+
+impl PoolExt for Pool {
+    async fn dedicated(&self) -> Result<Client, PoolError> {
+        let mut client = self.get().await?;
+        client.dedicated = true;
+        Ok(client)
+    }
+}
+
+/// Extension trait for [`Pool`] running queries which are retried on a
+/// fresh connection if the connection turns out to be broken.
+///
+/// Recycling a [`Client`] can't detect every broken connection, e.g. when
+/// the server was restarted and the connection hasn't noticed yet. With
+/// [`RecyclingMethod::Fast`] this is even more likely. Queries run via
+/// this trait don't fail in that case but are retried once using another
+/// [`Client`].
+///
+/// ```rust,no_run
+/// use deadpool_postgres::{Config, PoolError, ResilientClient, Runtime};
+/// use tokio_postgres::NoTls;
+///
+/// # async fn example() -> Result<(), PoolError> {
+/// let mut cfg = Config::new();
+/// cfg.dbname = Some("deadpool".to_string());
+/// let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls).unwrap();
+/// let rows = pool.query_retry("SELECT 1 + $1::INT", &[&41]).await?;
+/// assert_eq!(rows[0].get::<_, i32>(0), 42);
+/// # Ok(())
+/// # }
+/// ```
+pub trait ResilientClient {
+    /// Retrieves a [`Client`] from the [`Pool`] and executes the given
+    /// `query` using a cached [`Statement`] (see
+    /// [`ClientWrapper::prepare_cached()`]).
+    ///
+    /// If this fails because the connection has been closed or terminated
+    /// by the server (e.g. via `pg_terminate_backend()`), the [`Client`] is
+    /// removed from the [`Pool`] and the query is retried once using
+    /// another [`Client`]. Other errors reported by the server are returned
+    /// immediately as the query might have been executed already.
+    ///
+    /// **Important:** The query may be executed twice if the connection
+    /// breaks after the server received it. Only use this method for
+    /// queries which are safe to be retried, e.g. `SELECT` statements.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    fn query_retry(
+        &self,
+        query: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> impl Future<Output = Result<Vec<Row>, PoolError>> + Send;
+}
+
+impl ResilientClient for Pool {
+    async fn query_retry(
+        &self,
+        query: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, PoolError> {
+        let mut client = self.get().await?;
+        match query_cached(&client, query, params).await {
+            Err(e) if is_connection_error(&e) => {
+                // Free the slot of the broken connection before retrieving
+                // another one. Otherwise this could wait forever for a pool
+                // with a `max_size` of 1.
+                Object::invalidate(&mut client);
+                drop(client);
+                let client = self.get().await?;
+                Ok(query_cached(&client, query, params).await?)
+            }
+            result => Ok(result?),
+        }
+    }
+}
+
+async fn query_cached(
+    client: &Client,
+    query: &str,
+    params: &[&(dyn ToSql + Sync)],
+) -> Result<Vec<Row>, Error> {
+    let statement = client.prepare_cached(query).await?;
+    client.query(&statement, params).await
+}
+
+/// Indicates whether the given [`Error`] was caused by a broken connection
+/// rather than by the query itself.
+fn is_connection_error(e: &Error) -> bool {
+    e.is_closed()
+        || e.source().is_some_and(|e| e.is::<io::Error>())
+        // The server may report the termination of the connection before
+        // closing it.
+        || e.code().is_some_and(|code| {
+            [SqlState::ADMIN_SHUTDOWN, SqlState::CRASH_SHUTDOWN].contains(code)
+        })
+}
+
 type RecycleResult = managed::RecycleResult<Error>;
 type RecycleError = managed::RecycleError<Error>;
 
@@ -81,7 +209,12 @@ type RecycleError = managed::RecycleError<Error>;
 ///
 /// [`Manager`]: managed::Manager
 pub struct Manager {
+    /// [`ManagerConfig`] whose `recycling_method` is moved into
+    /// [`Manager::recycling_method`] on construction and never read.
     config: ManagerConfig,
+    /// [`RecyclingMethod`] which can be changed at runtime via
+    /// [`Manager::set_recycling_method()`].
+    recycling_method: RwLock<RecyclingMethod>,
     pg_config: PgConfig,
     connect: Box<dyn Connect>,
     /// [`StatementCaches`] of [`Client`]s handed out by the [`Pool`].
@@ -120,21 +253,47 @@ impl Manager {
     pub fn from_connect(
         pg_config: tokio_postgres::Config,
         connect: impl Connect + 'static,
-        config: ManagerConfig,
+        mut config: ManagerConfig,
     ) -> Self {
+        let recycling_method = std::mem::take(&mut config.recycling_method);
         Self {
+            recycling_method: RwLock::new(recycling_method),
             config,
             pg_config,
             connect: Box::new(connect),
             statement_caches: StatementCaches::default(),
         }
     }
+
+    /// Returns the [`RecyclingMethod`] currently used for recycling
+    /// connections.
+    #[must_use]
+    pub fn recycling_method(&self) -> RecyclingMethod {
+        self.recycling_method.read().unwrap().clone()
+    }
+
+    /// Changes the [`RecyclingMethod`] initially set via
+    /// [`ManagerConfig::recycling_method`].
+    ///
+    /// This can be called via [`Pool::manager()`] at runtime, e.g. for
+    /// switching to a more thorough method during maintenance. It affects
+    /// all subsequent recycles while connections which are currently being
+    /// recycled still use the previous method.
+    ///
+    /// [`Pool::manager()`]: managed::Pool::manager
+    pub fn set_recycling_method(&self, recycling_method: RecyclingMethod) {
+        *self.recycling_method.write().unwrap() = recycling_method;
+    }
 }
 
 impl fmt::Debug for Manager {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let config = ManagerConfig {
+            recycling_method: self.recycling_method(),
+            ..self.config.clone()
+        };
         f.debug_struct("Manager")
-            .field("config", &self.config)
+            .field("config", &config)
             .field("pg_config", &self.pg_config)
             //.field("connect", &self.connect)
             .field("statement_caches", &self.statement_caches)
@@ -147,28 +306,64 @@ impl managed::Manager for Manager {
     type Error = Error;
 
     async fn create(&self) -> Result<ClientWrapper, Error> {
-        let (client, conn_task) = self.connect.connect(&self.pg_config).await?;
-        let client_wrapper = ClientWrapper::new(client, conn_task);
+        let state = Arc::new(ConnectionState::default());
+        let (client, conn_task) = self
+            .connect
+            .connect_with_state(&self.pg_config, state.clone())
+            .await?;
+        let client_wrapper = ClientWrapper {
+            client,
+            conn_task,
+            state,
+            statement_cache: Arc::new(StatementCache::new(self.config.max_statements)),
+            dedicated: false,
+        };
+        for sql in &self.config.on_connect {
+            let _ = client_wrapper.simple_query(sql).await?;
+        }
         self.statement_caches
             .attach(&client_wrapper.statement_cache);
         Ok(client_wrapper)
     }
 
-    async fn recycle(&self, client: &mut ClientWrapper, _: &Metrics) -> RecycleResult {
+    #[cfg_attr(target_arch = "wasm32", allow(unused_variables))]
+    async fn recycle(&self, client: &mut ClientWrapper, metrics: &Metrics) -> RecycleResult {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(max_lifetime) = self.config.max_lifetime {
+            if metrics.age() > max_lifetime {
+                tracing::info!(target: "deadpool.postgres", "Connection could not be recycled: Maximum lifetime exceeded");
+                return Err(RecycleError::message("Maximum lifetime exceeded"));
+            }
+        }
         if client.is_closed() {
-            tracing::warn!(target: "deadpool.postgres", "Connection could not be recycled: Connection closed");
+            match client.last_connection_error() {
+                Some(e) => {
+                    tracing::warn!(target: "deadpool.postgres", "Connection could not be recycled: Connection closed: {}", e);
+                }
+                None => {
+                    tracing::warn!(target: "deadpool.postgres", "Connection could not be recycled: Connection closed");
+                }
+            }
             return Err(RecycleError::message("Connection closed"));
         }
-        match self.config.recycling_method.query() {
-            Some(sql) => match client.simple_query(sql).await {
-                Ok(_) => Ok(()),
-                Err(e) => {
-                    tracing::warn!(target: "deadpool.postgres", "Connection could not be recycled: {}", e);
-                    Err(e.into())
-                }
-            },
-            None => Ok(()),
+        let reset_listeners = self.config.reset_listeners.then_some("UNLISTEN *");
+        // Session state of dedicated clients is always reset no matter
+        // which recycling method is configured.
+        let recycling_method = if std::mem::take(&mut client.dedicated) {
+            RecyclingMethod::Clean
+        } else {
+            self.recycling_method()
+        };
+        for sql in [recycling_method.query(), reset_listeners]
+            .into_iter()
+            .flatten()
+        {
+            if let Err(e) = client.simple_query(sql).await {
+                tracing::warn!(target: "deadpool.postgres", "Connection could not be recycled: {}", e);
+                return Err(e.into());
+            }
         }
+        Ok(())
     }
 
     fn detach(&self, object: &mut ClientWrapper) {
@@ -186,11 +381,69 @@ pub trait Connect: Sync + Send {
         &self,
         pg_config: &PgConfig,
     ) -> BoxFuture<'_, Result<(PgClient, JoinHandle<()>), Error>>;
+
+    /// Establishes a new connection just like [`Connect::connect()`] and
+    /// records information about it in the given [`ConnectionState`], e.g.
+    /// the host which was connected to and the error which caused the
+    /// connection to be closed.
+    ///
+    /// This is used by the [`Manager`]. The default implementation calls
+    /// [`Connect::connect()`] and leaves the [`ConnectionState`] empty.
+    fn connect_with_state(
+        &self,
+        pg_config: &PgConfig,
+        state: Arc<ConnectionState>,
+    ) -> BoxFuture<'_, Result<(PgClient, JoinHandle<()>), Error>> {
+        drop(state);
+        self.connect(pg_config)
+    }
+}
+
+/// State of a connection which is shared between the task processing the
+/// connection and its [`ClientWrapper`].
+///
+/// See [`Connect::connect_with_state()`].
+#[derive(Debug, Default)]
+pub struct ConnectionState {
+    host: OnceLock<String>,
+    error: OnceLock<Error>,
+}
+
+impl ConnectionState {
+    /// Records the host which was connected to.
+    pub fn set_host(&self, host: String) {
+        let _ = self.host.set(host);
+    }
+
+    /// Returns the host which was connected to.
+    #[must_use]
+    pub fn host(&self) -> Option<&str> {
+        self.host.get().map(String::as_str)
+    }
+
+    /// Records the error which caused the connection to be closed. Only the
+    /// first error is kept.
+    pub fn set_error(&self, error: Error) {
+        let _ = self.error.set(error);
+    }
+
+    /// Returns the error which caused the connection to be closed.
+    #[must_use]
+    pub fn error(&self) -> Option<&Error> {
+        self.error.get()
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 /// Provides an implementation of [`Connect`] that establishes the connection
 /// using the `tokio_postgres` configuration itself.
+///
+/// If multiple hosts are configured they are tried one after another (or in
+/// random order if [`LoadBalanceHosts::Random`] is used) using a copy of the
+/// `tokio_postgres` configuration containing only that host. Hosts which are
+/// unreachable or don't satisfy the [`TargetSessionAttrs`] are skipped and
+/// only the error of the last attempt is returned if none of them succeeds.
+/// The host which was connected to is recorded in the [`ConnectionState`].
 #[derive(Debug)]
 pub struct ConfigConnectImpl<T>
 where
@@ -214,15 +467,41 @@ where
     fn connect(
         &self,
         pg_config: &PgConfig,
+    ) -> BoxFuture<'_, Result<(PgClient, JoinHandle<()>), Error>> {
+        self.connect_with_state(pg_config, Arc::default())
+    }
+
+    fn connect_with_state(
+        &self,
+        pg_config: &PgConfig,
+        state: Arc<ConnectionState>,
     ) -> BoxFuture<'_, Result<(PgClient, JoinHandle<()>), Error>> {
         let tls = self.tls.clone();
         let pg_config = pg_config.clone();
         Box::pin(async move {
-            let fut = pg_config.connect(tls);
-            let (client, connection) = fut.await?;
+            // `tokio_postgres` doesn't tell which of multiple hosts was used,
+            // so every host is connected to using its own configuration.
+            let mut last_error = None;
+            let mut connected = None;
+            for (host, config) in split_hosts(&pg_config) {
+                match config.connect(tls.clone()).await {
+                    Ok(conn) => {
+                        state.set_host(host);
+                        connected = Some(conn);
+                        break;
+                    }
+                    Err(e) => last_error = Some(e),
+                }
+            }
+            let (client, connection) = match (connected, last_error) {
+                (Some(conn), _) => conn,
+                (None, Some(e)) => return Err(e),
+                (None, None) => pg_config.connect(tls).await?,
+            };
             let conn_task = spawn(async move {
                 if let Err(e) = connection.await {
                     tracing::warn!(target: "deadpool.postgres", "Connection error: {}", e);
+                    state.set_error(e);
                 }
             });
             Ok((client, conn_task))
@@ -230,6 +509,99 @@ where
     }
 }
 
+/// Returns one copy of the given [`PgConfig`] for every configured host
+/// containing only that host together with its name. The hosts are shuffled
+/// if [`LoadBalanceHosts::Random`] is used.
+#[cfg(not(target_arch = "wasm32"))]
+fn split_hosts(pg_config: &PgConfig) -> Vec<(String, PgConfig)> {
+    use std::{
+        collections::hash_map::RandomState,
+        hash::{BuildHasher, Hasher},
+    };
+
+    let hosts = pg_config.get_hosts();
+    let hostaddrs = pg_config.get_hostaddrs();
+    let ports = pg_config.get_ports();
+    let mut configs = (0..hosts.len().max(hostaddrs.len()))
+        .map(|i| {
+            let mut config = without_hosts(pg_config);
+            let name = match hosts.get(i) {
+                Some(Host::Tcp(host)) => {
+                    let _ = config.host(host);
+                    host.clone()
+                }
+                #[cfg(unix)]
+                Some(Host::Unix(path)) => {
+                    let _ = config.host_path(path);
+                    path.to_string_lossy().into_owned()
+                }
+                None => hostaddrs[i].to_string(),
+            };
+            if let Some(hostaddr) = hostaddrs.get(i) {
+                let _ = config.hostaddr(*hostaddr);
+            }
+            // A single port applies to all hosts.
+            let port = match ports {
+                [port] => Some(port),
+                ports => ports.get(i),
+            };
+            if let Some(port) = port {
+                let _ = config.port(*port);
+            }
+            (name, config)
+        })
+        .collect::<Vec<_>>();
+    if pg_config.get_load_balance_hosts() == tokio_postgres::config::LoadBalanceHosts::Random {
+        for i in (1..configs.len()).rev() {
+            let random = RandomState::new().build_hasher().finish();
+            configs.swap(i, (random % (i as u64 + 1)) as usize);
+        }
+    }
+    configs
+}
+
+/// Returns a copy of the given [`PgConfig`] without any hosts, host
+/// addresses and ports.
+#[cfg(not(target_arch = "wasm32"))]
+fn without_hosts(pg_config: &PgConfig) -> PgConfig {
+    let mut config = PgConfig::new();
+    let _ = config
+        .ssl_mode(pg_config.get_ssl_mode())
+        .ssl_negotiation(pg_config.get_ssl_negotiation())
+        .keepalives(pg_config.get_keepalives())
+        .keepalives_idle(pg_config.get_keepalives_idle())
+        .target_session_attrs(pg_config.get_target_session_attrs())
+        .channel_binding(pg_config.get_channel_binding());
+    if let Some(user) = pg_config.get_user() {
+        let _ = config.user(user);
+    }
+    if let Some(password) = pg_config.get_password() {
+        let _ = config.password(password);
+    }
+    if let Some(dbname) = pg_config.get_dbname() {
+        let _ = config.dbname(dbname);
+    }
+    if let Some(options) = pg_config.get_options() {
+        let _ = config.options(options);
+    }
+    if let Some(application_name) = pg_config.get_application_name() {
+        let _ = config.application_name(application_name);
+    }
+    if let Some(connect_timeout) = pg_config.get_connect_timeout() {
+        let _ = config.connect_timeout(*connect_timeout);
+    }
+    if let Some(tcp_user_timeout) = pg_config.get_tcp_user_timeout() {
+        let _ = config.tcp_user_timeout(*tcp_user_timeout);
+    }
+    if let Some(keepalives_interval) = pg_config.get_keepalives_interval() {
+        let _ = config.keepalives_interval(keepalives_interval);
+    }
+    if let Some(keepalives_retries) = pg_config.get_keepalives_retries() {
+        let _ = config.keepalives_retries(keepalives_retries);
+    }
+    config
+}
+
 /// Structure holding a reference to all [`StatementCache`]s and providing
 /// access for clearing all caches and removing single statements from them.
 #[derive(Default, Debug)]
@@ -269,6 +641,24 @@ impl StatementCaches {
             }
         }
     }
+
+    /// Returns the sum of the [`StatementCacheStats`] of all connections
+    /// which are currently managed by a [`Manager`].
+    ///
+    /// The stats of closed connections are no longer included.
+    pub fn stats(&self) -> StatementCacheStats {
+        let caches = self.caches.lock().unwrap();
+        caches
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|cache| cache.stats())
+            .fold(StatementCacheStats::default(), |total, stats| {
+                StatementCacheStats {
+                    hits: total.hits + stats.hits,
+                    misses: total.misses + stats.misses,
+                }
+            })
+    }
 }
 
 impl fmt::Debug for StatementCache {
@@ -276,10 +666,23 @@ impl fmt::Debug for StatementCache {
         f.debug_struct("ClientWrapper")
             //.field("map", &self.map)
             .field("size", &self.size)
+            .field("max_size", &self.max_size)
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
             .finish()
     }
 }
 
+/// Number of hits and misses of a [`StatementCache`] as returned by
+/// [`StatementCache::stats()`] and [`StatementCaches::stats()`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct StatementCacheStats {
+    /// Number of [`Statement`]s which were found in the cache.
+    pub hits: u64,
+    /// Number of [`Statement`]s which had to be prepared.
+    pub misses: u64,
+}
+
 // Allows us to use owned keys in a `HashMap`, but still be able to call `get`
 // with borrowed keys instead of allocating them each time.
 #[derive(Debug, Eq, Hash, PartialEq)]
@@ -288,11 +691,22 @@ struct StatementCacheKey<'a> {
     types: Cow<'a, [Type]>,
 }
 
+/// Entry of a [`StatementCache`] remembering when it was last used.
+struct StatementCacheEntry {
+    stmt: Statement,
+    last_used: AtomicU64,
+}
+
 /// Representation of a cache of [`Statement`]s.
 ///
 /// [`StatementCache`] is bound to one [`Client`], and [`Statement`]s generated
 /// by that [`Client`] must not be used with other [`Client`]s.
 ///
+/// If a maximum size is configured via [`ManagerConfig::max_statements`]
+/// the least recently used [`Statement`] is evicted once the cache is full.
+/// Evicted [`Statement`]s are deallocated on the server as soon as they are
+/// no longer in use.
+///
 /// It can be used like that:
 /// ```rust,ignore
 /// let client = pool.get().await?;
@@ -308,15 +722,24 @@ struct StatementCacheKey<'a> {
 /// and [`ClientWrapper::prepare_typed_cached()`] methods instead (or the
 /// similar ones on [`Transaction`]).
 pub struct StatementCache {
-    map: RwLock<HashMap<StatementCacheKey<'static>, Statement>>,
+    map: RwLock<HashMap<StatementCacheKey<'static>, StatementCacheEntry>>,
     size: AtomicUsize,
+    max_size: Option<usize>,
+    /// Counter used for tracking the order in which entries were used.
+    clock: AtomicU64,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl StatementCache {
-    fn new() -> Self {
+    fn new(max_size: Option<usize>) -> Self {
         Self {
             map: RwLock::new(HashMap::new()),
             size: AtomicUsize::new(0),
+            max_size,
+            clock: AtomicU64::new(0),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         }
     }
 
@@ -325,6 +748,30 @@ impl StatementCache {
         self.size.load(Ordering::Relaxed)
     }
 
+    /// Returns the maximum size of this [`StatementCache`] if any.
+    ///
+    /// See [`ManagerConfig::max_statements`].
+    pub fn max_size(&self) -> Option<usize> {
+        self.max_size
+    }
+
+    /// Returns how often a [`Statement`] was found in this
+    /// [`StatementCache`] and how often it had to be prepared.
+    ///
+    /// A high number of misses compared to the number of hits indicates that
+    /// dynamically generated queries are evicting each other or that the
+    /// [`ManagerConfig::max_statements`] is too small.
+    pub fn stats(&self) -> StatementCacheStats {
+        StatementCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
     /// Clears this [`StatementCache`].
     ///
     /// **Important:** This only clears the [`StatementCache`] of one [`Client`]
@@ -348,7 +795,7 @@ impl StatementCache {
             types: Cow::Owned(types.to_owned()),
         };
         let mut map = self.map.write().unwrap();
-        let removed = map.remove(&key);
+        let removed = map.remove(&key).map(|entry| entry.stmt);
         if removed.is_some() {
             let _ = self.size.fetch_sub(1, Ordering::Relaxed);
         }
@@ -361,7 +808,16 @@ impl StatementCache {
             query: Cow::Borrowed(query),
             types: Cow::Borrowed(types),
         };
-        self.map.read().unwrap().get(&key).map(ToOwned::to_owned)
+        let stmt = self.map.read().unwrap().get(&key).map(|entry| {
+            entry.last_used.store(self.tick(), Ordering::Relaxed);
+            entry.stmt.clone()
+        });
+        let counter = match stmt {
+            Some(_) => &self.hits,
+            None => &self.misses,
+        };
+        let _ = counter.fetch_add(1, Ordering::Relaxed);
+        stmt
     }
 
     /// Inserts a [`Statement`] into this [`StatementCache`].
@@ -370,10 +826,30 @@ impl StatementCache {
             query: Cow::Owned(query.to_owned()),
             types: Cow::Owned(types.to_owned()),
         };
+        let entry = StatementCacheEntry {
+            stmt,
+            last_used: AtomicU64::new(self.tick()),
+        };
         let mut map = self.map.write().unwrap();
-        if map.insert(key, stmt).is_none() {
+        if map.insert(key, entry).is_none() {
             let _ = self.size.fetch_add(1, Ordering::Relaxed);
         }
+        if let Some(max_size) = self.max_size {
+            while map.len() > max_size {
+                let Some(lru_key) = map
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                    .map(|(key, _)| StatementCacheKey {
+                        query: key.query.clone(),
+                        types: key.types.clone(),
+                    })
+                else {
+                    break;
+                };
+                drop(map.remove(&lru_key));
+                let _ = self.size.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
     }
 
     /// Creates a new prepared [`Statement`] using this [`StatementCache`], if
@@ -384,6 +860,42 @@ impl StatementCache {
         self.prepare_typed(client, query, &[]).await
     }
 
+    /// Creates new prepared [`Statement`]s for all given `queries` using
+    /// this [`StatementCache`], if possible.
+    ///
+    /// Missing [`Statement`]s are prepared concurrently which allows
+    /// `tokio_postgres` to pipeline them. Queries which occur multiple times
+    /// are only prepared once. The [`StatementCache`] is only updated if all
+    /// of them were prepared successfully.
+    pub async fn prepare_all(
+        &self,
+        client: &PgClient,
+        queries: &[&str],
+    ) -> Result<Vec<Statement>, Error> {
+        let cached = queries
+            .iter()
+            .map(|query| self.get(query, &[]))
+            .collect::<Vec<_>>();
+        let mut missing = HashMap::new();
+        let mut missing_queries = Vec::new();
+        for (query, stmt) in queries.iter().zip(&cached) {
+            if stmt.is_none() && !missing.contains_key(query) {
+                let _ = missing.insert(*query, missing_queries.len());
+                missing_queries.push(*query);
+            }
+        }
+        let prepared =
+            try_join_all(missing_queries.iter().map(|query| client.prepare(query))).await?;
+        for (query, stmt) in missing_queries.iter().zip(&prepared) {
+            self.insert(query, &[], stmt.clone());
+        }
+        Ok(queries
+            .iter()
+            .zip(cached)
+            .map(|(query, stmt)| stmt.unwrap_or_else(|| prepared[missing[query]].clone()))
+            .collect())
+    }
+
     /// Creates a new prepared [`Statement`] with specifying its [`Type`]s
     /// explicitly using this [`StatementCache`], if possible.
     ///
@@ -415,8 +927,15 @@ pub struct ClientWrapper {
     /// wrapper is dropped.
     conn_task: JoinHandle<()>,
 
+    /// [`ConnectionState`] shared with the connection task.
+    state: Arc<ConnectionState>,
+
     /// [`StatementCache`] of this client.
     pub statement_cache: Arc<StatementCache>,
+
+    /// Whether this client was handed out via [`PoolExt::dedicated()`] and
+    /// its session state must be reset when being recycled.
+    dedicated: bool,
 }
 
 impl ClientWrapper {
@@ -427,10 +946,50 @@ impl ClientWrapper {
         Self {
             client,
             conn_task,
-            statement_cache: Arc::new(StatementCache::new()),
+            state: Arc::default(),
+            statement_cache: Arc::new(StatementCache::new(None)),
+            dedicated: false,
         }
     }
 
+    /// Returns the host this client is connected to.
+    ///
+    /// The host is recorded by the [`Connect`] implementation of the
+    /// [`Manager`] via [`ConnectionState::set_host()`]. This is useful for
+    /// debugging which of multiple hosts was picked, e.g. when using
+    /// [`TargetSessionAttrs::ReadWrite`]. Custom [`Connect`]
+    /// implementations which don't record the host return `None`.
+    #[must_use]
+    pub fn connected_host(&self) -> Option<&str> {
+        self.state.host()
+    }
+
+    /// Starts listening for notifications on the given `channel` by running
+    /// `LISTEN`. The channel name is quoted so it may contain arbitrary
+    /// characters.
+    ///
+    /// **Important:** The subscription outlives the [`Client`] being returned
+    /// to the [`Pool`]. Enable [`ManagerConfig::reset_listeners`] or use
+    /// [`RecyclingMethod::Clean`] so that it is not inherited by the next user
+    /// of the connection.
+    pub async fn listen(&self, channel: &str) -> Result<(), Error> {
+        let sql = format!("LISTEN \"{}\"", channel.replace('"', "\"\""));
+        let _ = self.client.simple_query(&sql).await?;
+        Ok(())
+    }
+
+    /// Returns the error which caused the connection task to stop.
+    ///
+    /// This makes it possible to find out why a client became closed, e.g.
+    /// because the server terminated the connection. It returns [`None`]
+    /// while the connection is still alive. The error is only known for
+    /// clients created by the [`Manager`] if its [`Connect`] implementation
+    /// supports [`Connect::connect_with_state()`].
+    #[must_use]
+    pub fn last_connection_error(&self) -> Option<&Error> {
+        self.state.error()
+    }
+
     /// Like [`tokio_postgres::Client::prepare()`], but uses an existing
     /// [`Statement`] from the [`StatementCache`] if possible.
     pub async fn prepare_cached(&self, query: &str) -> Result<Statement, Error> {
@@ -449,6 +1008,15 @@ impl ClientWrapper {
             .await
     }
 
+    /// Like [`ClientWrapper::prepare_cached()`], but prepares multiple
+    /// `queries` at once pipelining them. See
+    /// [`StatementCache::prepare_all()`] for details.
+    pub async fn prepare_cached_all(&self, queries: &[&str]) -> Result<Vec<Statement>, Error> {
+        self.statement_cache
+            .prepare_all(&self.client, queries)
+            .await
+    }
+
     /// Like [`tokio_postgres::Client::transaction()`], but returns a wrapped
     /// [`Transaction`] with a [`StatementCache`].
     #[allow(unused_lifetimes)] // false positive
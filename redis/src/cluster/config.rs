@@ -1,7 +1,9 @@
+use std::time::Duration;
+
 pub use crate::config::ConfigError;
 use crate::ConnectionInfo;
 
-use super::{CreatePoolError, Pool, PoolBuilder, PoolConfig, Runtime};
+use super::{ClusterClientBuilder, CreatePoolError, Pool, PoolBuilder, PoolConfig, Runtime};
 
 /// Configuration object.
 ///
@@ -61,6 +63,23 @@ pub struct Config {
     /// Default is `false`.
     #[cfg_attr(feature = "serde", serde(default))]
     pub read_from_replicas: bool,
+
+    /// Password used to authenticate with all nodes of the cluster.
+    ///
+    /// This overrides the password of the [`Config::urls`] and
+    /// [`Config::connections`].
+    pub password: Option<String>,
+
+    /// Timeout for establishing a new connection to a node.
+    pub connection_timeout: Option<Duration>,
+
+    /// Number of times a request is retried, e.g. when a node is moved.
+    pub retries: Option<u32>,
+
+    /// Check which is performed when recycling a connection. See
+    /// [`RecycleCheck`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recycle_check: RecycleCheck,
 }
 
 impl Config {
@@ -83,19 +102,26 @@ impl Config {
     ///
     /// See [`ConfigError`] for details.
     pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
-        let manager = match (&self.urls, &self.connections) {
-            (Some(urls), None) => super::Manager::new(
-                urls.iter().map(|url| url.as_str()).collect(),
-                self.read_from_replicas,
-            )?,
-            (None, Some(connections)) => {
-                super::Manager::new(connections.clone(), self.read_from_replicas)?
-            }
-            (None, None) => {
-                super::Manager::new(vec![ConnectionInfo::default()], self.read_from_replicas)?
-            }
+        let mut client = match (&self.urls, &self.connections) {
+            (Some(urls), None) => ClusterClientBuilder::new(urls.iter().map(|url| url.as_str())),
+            (None, Some(connections)) => ClusterClientBuilder::new(connections.clone()),
+            (None, None) => ClusterClientBuilder::new(vec![ConnectionInfo::default()]),
             (Some(_), Some(_)) => return Err(ConfigError::UrlAndConnectionSpecified),
         };
+        if self.read_from_replicas {
+            client = client.read_from_replicas();
+        }
+        if let Some(password) = &self.password {
+            client = client.password(password.clone());
+        }
+        if let Some(connection_timeout) = self.connection_timeout {
+            client = client.connection_timeout(connection_timeout);
+        }
+        if let Some(retries) = self.retries {
+            client = client.retries(retries);
+        }
+        let manager =
+            super::Manager::from_client_builder(client)?.with_recycle_check(self.recycle_check);
         let pool_config = self.get_pool_config();
         Ok(Pool::builder(manager).config(pool_config))
     }
@@ -116,6 +142,10 @@ impl Config {
             connections: None,
             pool: None,
             read_from_replicas: false,
+            password: None,
+            connection_timeout: None,
+            retries: None,
+            recycle_check: RecycleCheck::default(),
         }
     }
 }
@@ -127,6 +157,31 @@ impl Default for Config {
             connections: Some(vec![ConnectionInfo::default()]),
             pool: None,
             read_from_replicas: false,
+            password: None,
+            connection_timeout: None,
+            retries: None,
+            recycle_check: RecycleCheck::default(),
         }
     }
 }
+
+/// Possible checks performed when recycling a cluster connection.
+///
+/// The async cluster connection multiplexes commands across all nodes of the
+/// cluster and routes each command to a single node. Validating every node
+/// the connection is connected to is therefore not feasible.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RecycleCheck {
+    /// Send a `PING` to one of the nodes.
+    #[default]
+    Ping,
+
+    /// Run `CLUSTER INFO` on one of the nodes and make sure the cluster
+    /// state is `ok`.
+    ClusterInfo,
+
+    /// Don't check the connection at all and rely on the cluster client
+    /// reconnecting to nodes on its own.
+    Skip,
+}
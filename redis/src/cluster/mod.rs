@@ -13,7 +13,7 @@ use redis;
 pub use redis::cluster::{ClusterClient, ClusterClientBuilder};
 pub use redis::cluster_async::ClusterConnection;
 
-pub use self::config::{Config, ConfigError};
+pub use self::config::{Config, ConfigError, RecycleCheck};
 
 pub use deadpool::managed::reexports::*;
 deadpool::managed_reexports!(
@@ -105,6 +105,7 @@ impl ConnectionLike for Connection {
 pub struct Manager {
     client: ClusterClient,
     ping_number: AtomicUsize,
+    recycle_check: RecycleCheck,
 }
 
 // `redis::cluster_async::ClusterClient: !Debug`
@@ -113,6 +114,7 @@ impl std::fmt::Debug for Manager {
         f.debug_struct("Manager")
             .field("client", &format!("{:p}", &self.client))
             .field("ping_number", &self.ping_number)
+            .field("recycle_check", &self.recycle_check)
             .finish()
     }
 }
@@ -131,11 +133,31 @@ impl Manager {
         if read_from_replicas {
             client = client.read_from_replicas();
         }
+        Self::from_client_builder(client)
+    }
+
+    /// Creates a new [`Manager`] from the given [`ClusterClientBuilder`].
+    ///
+    /// This makes it possible to use all the options supported by the
+    /// [`ClusterClientBuilder`].
+    ///
+    /// # Errors
+    ///
+    /// If building the [`ClusterClient`] fails.
+    pub fn from_client_builder(client: ClusterClientBuilder) -> RedisResult<Self> {
         Ok(Self {
             client: client.build()?,
             ping_number: AtomicUsize::new(0),
+            recycle_check: RecycleCheck::default(),
         })
     }
+
+    /// Sets the [`RecycleCheck`] performed when recycling connections.
+    #[must_use]
+    pub fn with_recycle_check(mut self, recycle_check: RecycleCheck) -> Self {
+        self.recycle_check = recycle_check;
+        self
+    }
 }
 
 impl managed::Manager for Manager {
@@ -148,15 +170,31 @@ impl managed::Manager for Manager {
     }
 
     async fn recycle(&self, conn: &mut ClusterConnection, _: &Metrics) -> RecycleResult {
-        let ping_number = self.ping_number.fetch_add(1, Ordering::Relaxed).to_string();
-        let n = redis::cmd("PING")
-            .arg(&ping_number)
-            .query_async::<String>(conn)
-            .await?;
-        if n == ping_number {
-            Ok(())
-        } else {
-            Err(managed::RecycleError::message("Invalid PING response"))
+        match self.recycle_check {
+            RecycleCheck::Ping => {
+                let ping_number = self.ping_number.fetch_add(1, Ordering::Relaxed).to_string();
+                let n = redis::cmd("PING")
+                    .arg(&ping_number)
+                    .query_async::<String>(conn)
+                    .await?;
+                if n == ping_number {
+                    Ok(())
+                } else {
+                    Err(managed::RecycleError::message("Invalid PING response"))
+                }
+            }
+            RecycleCheck::ClusterInfo => {
+                let info = redis::cmd("CLUSTER")
+                    .arg("INFO")
+                    .query_async::<String>(conn)
+                    .await?;
+                if info.lines().any(|line| line.trim() == "cluster_state:ok") {
+                    Ok(())
+                } else {
+                    Err(managed::RecycleError::message("Cluster state is not ok"))
+                }
+            }
+            RecycleCheck::Skip => Ok(()),
         }
     }
 }
@@ -1,6 +1,6 @@
-use std::{fmt, path::PathBuf};
+use std::{fmt, path::PathBuf, sync::Arc, time::Duration};
 
-use redis::RedisError;
+use redis::{IntoConnectionInfo, RedisError};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -45,8 +45,27 @@ pub struct Config {
     /// [`redis::ConnectionInfo`] structure.
     pub connection: Option<ConnectionInfo>,
 
+    /// Database number to use.
+    ///
+    /// This overrides the database specified via [`Config::url`] or
+    /// [`Config::connection`].
+    pub db: Option<i64>,
+
+    /// [`Manager`] configuration.
+    ///
+    /// [`Manager`]: super::Manager
+    pub manager: Option<ManagerConfig>,
+
     /// Pool configuration.
     pub pool: Option<PoolConfig>,
+
+    /// Provider of the password used for every new connection. See
+    /// [`AuthProvider`].
+    ///
+    /// This can't be read from a configuration file and needs to be set
+    /// manually.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub auth_provider: Option<AuthProvider>,
 }
 
 impl Config {
@@ -69,16 +88,33 @@ impl Config {
     ///
     /// See [`ConfigError`] for details.
     pub fn builder(&self) -> Result<PoolBuilder, ConfigError> {
-        let manager = match (&self.url, &self.connection) {
-            (Some(url), None) => crate::Manager::new(url.as_str())?,
-            (None, Some(connection)) => crate::Manager::new(connection.clone())?,
-            (None, None) => crate::Manager::new(ConnectionInfo::default())?,
+        let mut connection_info = match (&self.url, &self.connection) {
+            (Some(url), None) => url.as_str().into_connection_info()?,
+            (None, Some(connection)) => connection.clone().into(),
+            (None, None) => ConnectionInfo::default().into(),
             (Some(_), Some(_)) => return Err(ConfigError::UrlAndConnectionSpecified),
         };
+        if let Some(db) = self.db {
+            connection_info.redis.db = db;
+        }
+        let mut manager =
+            crate::Manager::new_with_config(connection_info, self.get_manager_config())?;
+        if let Some(auth_provider) = &self.auth_provider {
+            manager = manager.with_auth_provider(auth_provider.clone());
+        }
         let pool_config = self.get_pool_config();
         Ok(Pool::builder(manager).config(pool_config))
     }
 
+    /// Returns [`ManagerConfig`] which can be used to construct a
+    /// [`Manager`].
+    ///
+    /// [`Manager`]: super::Manager
+    #[must_use]
+    pub fn get_manager_config(&self) -> ManagerConfig {
+        self.manager.clone().unwrap_or_default()
+    }
+
     /// Returns [`deadpool::managed::PoolConfig`] which can be used to construct
     /// a [`deadpool::managed::Pool`] instance.
     #[must_use]
@@ -93,7 +129,10 @@ impl Config {
         Config {
             url: Some(url.into()),
             connection: None,
+            db: None,
+            manager: None,
             pool: None,
+            auth_provider: None,
         }
     }
 
@@ -104,7 +143,10 @@ impl Config {
         Config {
             url: None,
             connection: Some(connection_info.into()),
+            db: None,
+            manager: None,
             pool: None,
+            auth_provider: None,
         }
     }
 }
@@ -114,11 +156,128 @@ impl Default for Config {
         Self {
             url: None,
             connection: Some(ConnectionInfo::default()),
+            db: None,
+            manager: None,
             pool: None,
+            auth_provider: None,
         }
     }
 }
 
+/// Provider of short-lived passwords (e.g. IAM authentication tokens) used
+/// for authenticating new connections.
+///
+/// It is called by the [`Manager`] every time a new connection is created
+/// and the returned token replaces the password of the configured
+/// [`ConnectionInfo`]. The username is taken from the [`ConnectionInfo`] as
+/// well.
+///
+/// Existing connections are not authenticated again when being recycled. As
+/// tokens usually only need to be valid while establishing a connection this
+/// is not necessary. Use [`PoolConfig::max_lifetime`] if connections should
+/// be replaced periodically nonetheless.
+///
+/// [`Manager`]: super::Manager
+/// [`PoolConfig::max_lifetime`]: deadpool::managed::PoolConfig::max_lifetime
+#[derive(Clone)]
+pub struct AuthProvider(Arc<dyn Fn() -> String + Send + Sync>);
+
+impl AuthProvider {
+    /// Creates a new [`AuthProvider`] from the given function returning the
+    /// current token.
+    pub fn new(provider: impl Fn() -> String + Send + Sync + 'static) -> Self {
+        Self(Arc::new(provider))
+    }
+
+    /// Returns the current token.
+    pub(crate) fn token(&self) -> String {
+        (self.0)()
+    }
+}
+
+impl fmt::Debug for AuthProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AuthProvider").finish_non_exhaustive()
+    }
+}
+
+/// Configuration object for a [`Manager`].
+///
+/// This makes it possible to specify which [`RecyclingMethod`] should be
+/// used when retrieving existing connections from the [`Pool`] and which
+/// timeouts should be used by the connections.
+///
+/// [`Manager`]: super::Manager
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde"))]
+pub struct ManagerConfig {
+    /// Method of how a connection is recycled. See [`RecyclingMethod`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub recycling_method: RecyclingMethod,
+
+    /// Timeout for establishing a new connection.
+    ///
+    /// Default: No timeout
+    pub connection_timeout: Option<Duration>,
+
+    /// Timeout for receiving the response of a command.
+    ///
+    /// This is applied by the underlying [`MultiplexedConnection`] to every
+    /// single command (or pipeline) sent over a connection. It doesn't limit
+    /// how long a [`Connection`] may be checked out from the [`Pool`] which
+    /// is not bounded by any timeout. Commands exceeding it fail with an
+    /// error for which [`RedisError::is_timeout()`] returns `true`.
+    ///
+    /// Default: No timeout
+    ///
+    /// [`Connection`]: super::Connection
+    /// [`MultiplexedConnection`]: redis::aio::MultiplexedConnection
+    pub response_timeout: Option<Duration>,
+
+    /// Send `CLIENT TRACKING OFF` when recycling [`RESP3`] connections.
+    ///
+    /// Client side caching enabled by a user of a connection causes the
+    /// server to send invalidation push messages for all keys read via this
+    /// connection, including those read by later users. Enabling this
+    /// prevents those messages from leaking to the next user at the cost
+    /// of one more command in the recycle pipeline. It must not be enabled
+    /// if tracking is meant to stay on for the whole lifetime of a
+    /// connection. This has no effect with [`RecyclingMethod::Fast`].
+    ///
+    /// Default: `false`
+    ///
+    /// [`RESP3`]: ProtocolVersion::RESP3
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub reset_client_tracking: bool,
+}
+
+/// Possible methods of how a connection is recycled.
+///
+/// The default is [`Verified`] which makes sure the connection still works.
+///
+/// [`Verified`]: RecyclingMethod::Verified
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+#[cfg_attr(feature = "serde", serde(crate = "serde"))]
+pub enum RecyclingMethod {
+    /// Return the connection without sending any command to the server.
+    ///
+    /// This saves a round trip when retrieving a connection but broken
+    /// connections are only detected once they are used. Keys watched via
+    /// `WATCH` are not reset either so this should only be used if `WATCH`
+    /// is never used or always followed by `EXEC`, `DISCARD` or `UNWATCH`.
+    /// The database isn't selected again either, so a connection on which
+    /// the previous user ran `SELECT` stays on the database chosen by it.
+    Fast,
+
+    /// Run `UNWATCH`, `SELECT` and `PING` before returning the connection
+    /// which resets any watched keys and the selected database and makes
+    /// sure the connection still works.
+    #[default]
+    Verified,
+}
+
 /// This is a 1:1 copy of the [`redis::ConnectionAddr`] enumeration (excluding `tls_params` since it is entirely opaque to consumers).
 ///
 /// This is duplicated here in order to add support for the
@@ -230,7 +389,7 @@ impl From<redis::ConnectionInfo> for ConnectionInfo {
     }
 }
 
-impl redis::IntoConnectionInfo for ConnectionInfo {
+impl IntoConnectionInfo for ConnectionInfo {
     fn into_connection_info(self) -> RedisResult<redis::ConnectionInfo> {
         Ok(self.into())
     }
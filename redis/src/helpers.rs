@@ -0,0 +1,160 @@
+//! Convenience methods for running single commands on a [`Pool`] and
+//! batched commands on a [`Connection`].
+
+use std::future::Future;
+
+use redis::{Cmd, FromRedisValue, RedisResult, ToRedisArgs};
+
+use crate::{Connection, Object, Pool, PoolError};
+
+/// Extension trait for [`Pool`] running single commands without having to
+/// retrieve a [`Connection`] first.
+///
+/// ```rust,no_run
+/// use deadpool_redis::{redis::cmd, Config, PoolError, PoolExt, Runtime};
+///
+/// # async fn example() -> Result<(), PoolError> {
+/// let pool = Config::from_url("redis://127.0.0.1/")
+///     .create_pool(Some(Runtime::Tokio1))
+///     .unwrap();
+/// pool.cmd::<()>(cmd("SET").arg("key").arg(42)).await?;
+/// let value: i64 = pool.cmd(cmd("GET").arg("key")).await?;
+/// assert_eq!(value, 42);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`Connection`]: crate::Connection
+pub trait PoolExt {
+    /// Retrieves a [`Connection`] from the [`Pool`], runs the given `cmd` on
+    /// it and returns the connection to the [`Pool`] afterwards.
+    ///
+    /// If the command fails with an unrecoverable error (see
+    /// [`RedisError::is_unrecoverable_error()`]) the connection is removed
+    /// from the [`Pool`] instead of being returned to it.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    ///
+    /// [`Connection`]: crate::Connection
+    /// [`RedisError::is_unrecoverable_error()`]: redis::RedisError::is_unrecoverable_error
+    fn cmd<T>(&self, cmd: &Cmd) -> impl Future<Output = Result<T, PoolError>> + Send
+    where
+        T: FromRedisValue + Send;
+
+    /// Sends a `PING` using a [`Connection`] of the [`Pool`] and returns the
+    /// response of the server.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    ///
+    /// [`Connection`]: crate::Connection
+    fn ping(&self) -> impl Future<Output = Result<String, PoolError>> + Send;
+
+    /// Retrieves a [`Connection`] from the [`Pool`] and fetches the values
+    /// of the given `keys` using [`Connection::mget_chunked()`].
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    ///
+    /// [`Connection`]: crate::Connection
+    fn mget_chunked<I, K, T>(
+        &self,
+        keys: I,
+        chunk_size: usize,
+    ) -> impl Future<Output = Result<Vec<Option<T>>, PoolError>> + Send
+    where
+        I: IntoIterator<Item = K> + Send,
+        I::IntoIter: Send,
+        K: ToRedisArgs + Send,
+        T: FromRedisValue + Send;
+}
+
+impl PoolExt for Pool {
+    async fn cmd<T>(&self, cmd: &Cmd) -> Result<T, PoolError>
+    where
+        T: FromRedisValue + Send,
+    {
+        let mut conn = self.get().await?;
+        cmd.query_async(&mut conn).await.map_err(|e| {
+            if e.is_unrecoverable_error() {
+                Object::invalidate(&mut conn.conn);
+            }
+            e.into()
+        })
+    }
+
+    async fn ping(&self) -> Result<String, PoolError> {
+        self.cmd(&redis::cmd("PING")).await
+    }
+
+    async fn mget_chunked<I, K, T>(
+        &self,
+        keys: I,
+        chunk_size: usize,
+    ) -> Result<Vec<Option<T>>, PoolError>
+    where
+        I: IntoIterator<Item = K> + Send,
+        I::IntoIter: Send,
+        K: ToRedisArgs + Send,
+        T: FromRedisValue + Send,
+    {
+        let mut conn = self.get().await?;
+        conn.mget_chunked(keys, chunk_size).await.map_err(|e| {
+            if e.is_unrecoverable_error() {
+                Object::invalidate(&mut conn.conn);
+            }
+            e.into()
+        })
+    }
+}
+
+impl Connection {
+    /// Fetches the values of the given `keys` by sending one `MGET` command
+    /// per `chunk_size` keys.
+    ///
+    /// This avoids sending a single oversized command when fetching a large
+    /// number of keys. The values are returned in the order of the `keys`
+    /// with `None` for missing keys.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`RedisError`] encountered. The values of the chunks
+    /// fetched before are discarded in that case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is `0`.
+    ///
+    /// [`RedisError`]: redis::RedisError
+    pub async fn mget_chunked<I, K, T>(
+        &mut self,
+        keys: I,
+        chunk_size: usize,
+    ) -> RedisResult<Vec<Option<T>>>
+    where
+        I: IntoIterator<Item = K>,
+        K: ToRedisArgs,
+        T: FromRedisValue,
+    {
+        assert!(chunk_size > 0, "chunk_size must be greater than 0");
+        let mut keys = keys.into_iter().peekable();
+        let mut values = Vec::new();
+        while keys.peek().is_some() {
+            let mut cmd = redis::cmd("MGET");
+            for key in keys.by_ref().take(chunk_size) {
+                let _ = cmd.arg(key);
+            }
+            let chunk: Vec<Option<T>> = cmd.query_async(self).await?;
+            values.extend(chunk);
+        }
+        Ok(values)
+    }
+}
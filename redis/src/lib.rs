@@ -24,6 +24,9 @@
 #[cfg(feature = "cluster")]
 pub mod cluster;
 mod config;
+#[cfg(feature = "helpers")]
+mod helpers;
+pub mod pubsub;
 
 #[cfg(feature = "sentinel")]
 pub mod sentinel;
@@ -42,7 +45,13 @@ use redis::{
 pub use redis;
 
 pub use self::config::{
-    Config, ConfigError, ConnectionAddr, ConnectionInfo, ProtocolVersion, RedisConnectionInfo,
+    AuthProvider, Config, ConfigError, ConnectionAddr, ConnectionInfo, ManagerConfig,
+    ProtocolVersion, RecyclingMethod, RedisConnectionInfo,
+};
+#[cfg(feature = "helpers")]
+pub use self::helpers::PoolExt;
+pub use self::pubsub::{
+    Connection as PubSubConnection, Manager as PubSubManager, Pool as PubSubPool,
 };
 
 pub use deadpool::managed::reexports::*;
@@ -131,6 +140,8 @@ pub struct Manager {
     client: Client,
     ping_number: AtomicUsize,
     connection_config: AsyncConnectionConfig,
+    config: ManagerConfig,
+    auth_provider: Option<AuthProvider>,
 }
 
 // `redis::AsyncConnectionConfig: !Debug`
@@ -139,6 +150,8 @@ impl std::fmt::Debug for Manager {
         f.debug_struct("Manager")
             .field("client", &self.client)
             .field("ping_number", &self.ping_number)
+            .field("config", &self.config)
+            .field("auth_provider", &self.auth_provider)
             .finish()
     }
 }
@@ -150,7 +163,7 @@ impl Manager {
     ///
     /// If establishing a new [`Client`] fails.
     pub fn new<T: IntoConnectionInfo>(params: T) -> RedisResult<Self> {
-        Self::from_config(params, AsyncConnectionConfig::default())
+        Self::new_with_config(params, ManagerConfig::default())
     }
 
     /// Creates a new [`Manager`] from the given `params` and [`AsyncConnectionConfig`].
@@ -166,8 +179,40 @@ impl Manager {
             client: Client::open(params)?,
             ping_number: AtomicUsize::new(0),
             connection_config,
+            config: ManagerConfig::default(),
+            auth_provider: None,
         })
     }
+
+    /// Creates a new [`Manager`] from the given `params` and [`ManagerConfig`].
+    ///
+    /// # Errors
+    ///
+    /// If establishing a new [`Client`] fails.
+    pub fn new_with_config<T: IntoConnectionInfo>(
+        params: T,
+        config: ManagerConfig,
+    ) -> RedisResult<Self> {
+        let mut connection_config = AsyncConnectionConfig::new();
+        if let Some(connection_timeout) = config.connection_timeout {
+            connection_config = connection_config.set_connection_timeout(connection_timeout);
+        }
+        if let Some(response_timeout) = config.response_timeout {
+            connection_config = connection_config.set_response_timeout(response_timeout);
+        }
+        Ok(Self {
+            config,
+            ..Self::from_config(params, connection_config)?
+        })
+    }
+
+    /// Sets the [`AuthProvider`] which is called for the password of every
+    /// new connection.
+    #[must_use]
+    pub fn with_auth_provider(mut self, auth_provider: AuthProvider) -> Self {
+        self.auth_provider = Some(auth_provider);
+        self
+    }
 }
 
 impl managed::Manager for Manager {
@@ -175,19 +220,51 @@ impl managed::Manager for Manager {
     type Error = RedisError;
 
     async fn create(&self) -> Result<MultiplexedConnection, RedisError> {
-        let conn = self
-            .client
-            .get_multiplexed_async_connection_with_config(&self.connection_config)
-            .await?;
+        // The `redis` crate already sends `SELECT` when establishing a new
+        // connection using a database other than `0`.
+        let conn = match &self.auth_provider {
+            Some(auth_provider) => {
+                // The `redis` crate authenticates new connections using the
+                // password of the `Client` so a new one is needed per token.
+                let mut connection_info = self.client.get_connection_info().clone();
+                connection_info.redis.password = Some(auth_provider.token());
+                Client::open(connection_info)?
+                    .get_multiplexed_async_connection_with_config(&self.connection_config)
+                    .await?
+            }
+            None => {
+                self.client
+                    .get_multiplexed_async_connection_with_config(&self.connection_config)
+                    .await?
+            }
+        };
         Ok(conn)
     }
 
     async fn recycle(&self, conn: &mut MultiplexedConnection, _: &Metrics) -> RecycleResult {
+        if self.config.recycling_method == RecyclingMethod::Fast {
+            return Ok(());
+        }
         let ping_number = self.ping_number.fetch_add(1, Ordering::Relaxed).to_string();
-        // Using pipeline to avoid roundtrip for UNWATCH
-        let (n,) = redis::Pipeline::with_capacity(2)
+        let connection_info = &self.client.get_connection_info().redis;
+        // Using pipeline to avoid roundtrip for UNWATCH and SELECT. The
+        // database is selected again in case it was changed by the previous
+        // user of the connection.
+        let mut pipeline = redis::Pipeline::with_capacity(4);
+        let _ = pipeline
             .cmd("UNWATCH")
             .ignore()
+            .cmd("SELECT")
+            .arg(connection_info.db)
+            .ignore();
+        // Client side caching causes the server to send invalidation push
+        // messages for keys read by the previous user of the connection.
+        if self.config.reset_client_tracking
+            && connection_info.protocol == redis::ProtocolVersion::RESP3
+        {
+            let _ = pipeline.cmd("CLIENT").arg("TRACKING").arg("OFF").ignore();
+        }
+        let (n,) = pipeline
             .cmd("PING")
             .arg(&ping_number)
             .query_async::<(String,)>(conn)
@@ -0,0 +1,125 @@
+//! This module extends the library to support dedicated Pub/Sub connections.
+//!
+//! Connections which are subscribed to channels can't be shared via a
+//! [`redis::aio::MultiplexedConnection`] and therefore need their own
+//! [`Pool`].
+use std::ops::{Deref, DerefMut};
+
+use deadpool::managed;
+use futures_util::{FutureExt, StreamExt};
+use redis::{aio::PubSub, Client, IntoConnectionInfo, RedisError, RedisResult};
+
+use crate::ConfigError;
+
+pub use deadpool::managed::reexports::*;
+deadpool::managed_reexports!("redis_pubsub", Manager, Connection, RedisError, ConfigError);
+
+type RecycleResult = managed::RecycleResult<RedisError>;
+
+/// Wrapper around [`redis::aio::PubSub`].
+///
+/// This structure derefs to [`redis::aio::PubSub`] and can therefore be used
+/// just like a regular [`redis::aio::PubSub`].
+#[allow(missing_debug_implementations)] // `redis::aio::PubSub: !Debug`
+pub struct Connection {
+    conn: Object,
+}
+
+impl Connection {
+    /// Takes this [`Connection`] from its [`Pool`] permanently.
+    ///
+    /// This reduces the size of the [`Pool`].
+    #[must_use]
+    pub fn take(this: Self) -> PubSub {
+        Object::take(this.conn)
+    }
+}
+
+impl From<Object> for Connection {
+    fn from(conn: Object) -> Self {
+        Self { conn }
+    }
+}
+
+impl Deref for Connection {
+    type Target = PubSub;
+
+    fn deref(&self) -> &PubSub {
+        &self.conn
+    }
+}
+
+impl DerefMut for Connection {
+    fn deref_mut(&mut self) -> &mut PubSub {
+        &mut self.conn
+    }
+}
+
+impl AsRef<PubSub> for Connection {
+    fn as_ref(&self) -> &PubSub {
+        &self.conn
+    }
+}
+
+impl AsMut<PubSub> for Connection {
+    fn as_mut(&mut self) -> &mut PubSub {
+        &mut self.conn
+    }
+}
+
+/// [`Manager`] for creating and recycling [`redis::aio::PubSub`] connections.
+///
+/// Recycling a connection unsubscribes it from all channels and patterns.
+/// The replies to these commands also verify that the connection is still
+/// alive. Messages which were received before those replies are discarded
+/// so they are never returned to the next user of the connection.
+///
+/// Connections which are removed from the [`Pool`] are closed when being
+/// dropped which also ends all of their subscriptions.
+///
+/// [`Manager`]: managed::Manager
+#[derive(Debug)]
+pub struct Manager {
+    client: Client,
+}
+
+impl Manager {
+    /// Creates a new [`Manager`] from the given `params`.
+    ///
+    /// # Errors
+    ///
+    /// If establishing a new [`Client`] fails.
+    pub fn new<T: IntoConnectionInfo>(params: T) -> RedisResult<Self> {
+        Ok(Self {
+            client: Client::open(params)?,
+        })
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = PubSub;
+    type Error = RedisError;
+
+    async fn create(&self) -> Result<PubSub, RedisError> {
+        let conn = self.client.get_async_pubsub().await?;
+        Ok(conn)
+    }
+
+    async fn recycle(&self, conn: &mut PubSub, _: &Metrics) -> RecycleResult {
+        // Calling `UNSUBSCRIBE` and `PUNSUBSCRIBE` without any arguments
+        // unsubscribes from all channels and patterns.
+        conn.unsubscribe(Vec::<String>::new()).await?;
+        conn.punsubscribe(Vec::<String>::new()).await?;
+        // Redis delivers messages in order. Every message published before
+        // the replies to the commands above is therefore already buffered
+        // and can be discarded without waiting.
+        let mut messages = conn.on_message();
+        loop {
+            match messages.next().now_or_never() {
+                Some(Some(_)) => {}
+                Some(None) => return Err(managed::RecycleError::message("Connection closed")),
+                None => return Ok(()),
+            }
+        }
+    }
+}
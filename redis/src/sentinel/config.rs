@@ -59,6 +59,13 @@ pub struct Config {
     pub node_connection_info: Option<SentinelNodeConnectionInfo>,
     /// Pool configuration.
     pub pool: Option<PoolConfig>,
+    /// Verify that the server of a connection is still the master when
+    /// recycling it. This makes sure that no connections to the old master
+    /// are used after a failover. New connections are always verified.
+    /// Only used with [`SentinelServerType::Master`]. Default value is
+    /// `true`.
+    #[serde(default = "default_verify_master_on_recycle")]
+    pub verify_master_on_recycle: bool,
 }
 
 impl Config {
@@ -101,7 +108,8 @@ impl Config {
                 self.server_type,
             )?,
             (Some(_), Some(_)) => return Err(ConfigError::UrlAndConnectionSpecified),
-        };
+        }
+        .with_verify_master_on_recycle(self.verify_master_on_recycle);
         let pool_config = self.get_pool_config();
         Ok(Pool::builder(manager).config(pool_config))
     }
@@ -128,6 +136,7 @@ impl Config {
             server_type,
             pool: None,
             node_connection_info: None,
+            verify_master_on_recycle: default_verify_master_on_recycle(),
         }
     }
 
@@ -155,6 +164,7 @@ impl Default for Config {
             master_name: default_master_name(),
             pool: None,
             node_connection_info: None,
+            verify_master_on_recycle: default_verify_master_on_recycle(),
         }
     }
 }
@@ -163,6 +173,10 @@ fn default_master_name() -> String {
     "mymaster".to_string()
 }
 
+fn default_verify_master_on_recycle() -> bool {
+    true
+}
+
 /// This type is a wrapper for [`redis::sentinel::SentinelServerType`] for serialize/deserialize.
 #[derive(Debug, Clone, Copy, Default)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -1,13 +1,16 @@
 //! This module extends the library to support Redis Cluster.
 use std::{
     ops::{Deref, DerefMut},
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex as StdMutex,
+    },
 };
 
 use redis;
 use redis::aio::MultiplexedConnection;
 use redis::sentinel::SentinelClient;
-use redis::{aio::ConnectionLike, IntoConnectionInfo, RedisError, RedisResult};
+use redis::{aio::ConnectionLike, Client, IntoConnectionInfo, RedisError, RedisResult};
 use tokio::sync::Mutex;
 
 use deadpool::managed;
@@ -106,9 +109,21 @@ impl ConnectionLike for Connection {
 
 /// [`Manager`] for creating and recycling [`redis::aio::MultiplexedConnection`] connections.
 ///
+/// When using [`SentinelServerType::Master`] the address of the master is
+/// cached so that creating new connections doesn't query the sentinels
+/// every time. Every connection created from the cached address is asked
+/// for its `ROLE`. After a failover the old master usually stays reachable
+/// as a replica, so the master is looked up again if the server is no
+/// longer the master or connecting to it fails. Connections which were
+/// created before the failover are only detected when recycling them if
+/// [`Config::verify_master_on_recycle`] is enabled.
+///
 /// [`Manager`]: managed::Manager
 pub struct Manager {
     client: Mutex<SentinelClient>,
+    server_type: SentinelServerType,
+    master: StdMutex<Option<Client>>,
+    verify_master_on_recycle: bool,
     ping_number: AtomicUsize,
 }
 
@@ -116,6 +131,9 @@ impl std::fmt::Debug for Manager {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Manager")
             .field("client", &format!("{:p}", &self.client))
+            .field("server_type", &self.server_type)
+            .field("master", &self.master)
+            .field("verify_master_on_recycle", &self.verify_master_on_recycle)
             .field("ping_number", &self.ping_number)
             .finish()
     }
@@ -140,9 +158,21 @@ impl Manager {
                 node_connection_info.map(|i| i.into()),
                 server_type.into(),
             )?),
+            server_type,
+            master: StdMutex::new(None),
+            verify_master_on_recycle: true,
             ping_number: AtomicUsize::new(0),
         })
     }
+
+    /// Enables or disables verifying that the server of a connection is still
+    /// the master when recycling it. This is enabled by default and only
+    /// used with [`SentinelServerType::Master`].
+    #[must_use]
+    pub fn with_verify_master_on_recycle(mut self, verify_master_on_recycle: bool) -> Self {
+        self.verify_master_on_recycle = verify_master_on_recycle;
+        self
+    }
 }
 
 impl managed::Manager for Manager {
@@ -150,17 +180,52 @@ impl managed::Manager for Manager {
     type Error = RedisError;
 
     async fn create(&self) -> Result<MultiplexedConnection, RedisError> {
-        let mut client = self.client.lock().await;
-        let conn = client.get_async_connection().await?;
+        let master = self.master.lock().unwrap().clone();
+        if let Some(master) = master {
+            if let Ok(mut conn) = master.get_multiplexed_async_connection().await {
+                if is_master(&mut conn).await.unwrap_or(false) {
+                    return Ok(conn);
+                }
+            }
+            // The master might have changed. Ask the sentinels again.
+            *self.master.lock().unwrap() = None;
+        }
+        let client = self.client.lock().await.async_get_client().await?;
+        let conn = client.get_multiplexed_async_connection().await?;
+        if matches!(self.server_type, SentinelServerType::Master) {
+            *self.master.lock().unwrap() = Some(client);
+        }
         Ok(conn)
     }
 
     async fn recycle(&self, conn: &mut MultiplexedConnection, _: &Metrics) -> RecycleResult {
         let ping_number = self.ping_number.fetch_add(1, Ordering::Relaxed).to_string();
-        let n = redis::cmd("PING")
-            .arg(&ping_number)
-            .query_async::<String>(conn)
-            .await?;
+        let n = if self.verify_master_on_recycle
+            && matches!(self.server_type, SentinelServerType::Master)
+        {
+            // The connection doesn't expose the address of its server. So
+            // instead of comparing it with the current master the server is
+            // asked for its role which is pipelined with the `PING`.
+            let (n, role) = redis::pipe()
+                .cmd("PING")
+                .arg(&ping_number)
+                .cmd("ROLE")
+                .query_async::<(String, Vec<redis::Value>)>(conn)
+                .await?;
+            if !role_is_master(&role)? {
+                // A failover happened. Don't use the cached master anymore.
+                *self.master.lock().unwrap() = None;
+                return Err(managed::RecycleError::message(
+                    "Server is no longer the master",
+                ));
+            }
+            n
+        } else {
+            redis::cmd("PING")
+                .arg(&ping_number)
+                .query_async::<String>(conn)
+                .await?
+        };
         if n == ping_number {
             Ok(())
         } else {
@@ -168,3 +233,21 @@ impl managed::Manager for Manager {
         }
     }
 }
+
+/// Asks the server of the given connection whether it is a master.
+async fn is_master(conn: &mut MultiplexedConnection) -> RedisResult<bool> {
+    let role = redis::cmd("ROLE")
+        .query_async::<Vec<redis::Value>>(conn)
+        .await?;
+    role_is_master(&role)
+}
+
+/// Checks whether the given reply of the `ROLE` command is the one of a
+/// master.
+fn role_is_master(role: &[redis::Value]) -> RedisResult<bool> {
+    let role = role
+        .first()
+        .map(redis::from_redis_value::<String>)
+        .transpose()?;
+    Ok(role.as_deref() == Some("master"))
+}
@@ -191,3 +191,366 @@ async fn test_recycled_with_watch() {
         );
     }
 }
+
+#[tokio::test]
+async fn test_pubsub_recycled() {
+    use deadpool_redis::{redis::Msg, PubSubManager, PubSubPool};
+    use futures::StreamExt;
+
+    const CHANNEL: &str = "deadpool/pubsub_test_channel";
+    const SYNC_CHANNEL: &str = "deadpool/pubsub_test_sync_channel";
+
+    let cfg = Config::from_env();
+    let manager = PubSubManager::new(cfg.redis.url.unwrap()).unwrap();
+    let pool = PubSubPool::builder(manager)
+        .max_size(1)
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    let publisher = create_pool();
+
+    async fn publish(pool: &deadpool_redis::Pool, msg: &str) {
+        let mut conn = pool.get().await.unwrap();
+        cmd("PUBLISH")
+            .arg(CHANNEL)
+            .arg(msg)
+            .query_async::<i64>(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    {
+        let mut conn = pool.get().await.unwrap();
+        conn.subscribe(CHANNEL).await.unwrap();
+        // This message is never read by the first user of the connection.
+        publish(&publisher, "first").await;
+    }
+
+    {
+        let mut conn = pool.get().await.unwrap();
+        publish(&publisher, "second").await;
+        // Messages are delivered in order. Once this subscription is
+        // confirmed every message published before has been received.
+        conn.subscribe(SYNC_CHANNEL).await.unwrap();
+        let msg: Option<Option<Msg>> = conn.on_message().next().now_or_never();
+        assert!(msg.is_none(), "a message of the previous user was returned");
+    }
+
+    assert_eq!(
+        pool.object_counts().created,
+        1,
+        "the pubsub connection was not recycled"
+    );
+}
+
+#[tokio::test]
+async fn test_recycling_method_fast() {
+    use deadpool_redis::{ManagerConfig, PoolConfig, RecyclingMethod};
+
+    let mut cfg = Config::from_env();
+    cfg.redis.manager = Some(ManagerConfig {
+        recycling_method: RecyclingMethod::Fast,
+        ..Default::default()
+    });
+    cfg.redis.pool = Some(PoolConfig::new(1));
+    let pool = cfg.redis.create_pool(Some(Runtime::Tokio1)).unwrap();
+
+    let client_id = {
+        let mut conn = pool.get().await.unwrap();
+        cmd("CLIENT")
+            .arg("ID")
+            .query_async::<i64>(&mut conn)
+            .await
+            .unwrap()
+    };
+
+    // Kill the pooled connection using a connection from another pool
+    let killer_pool = create_pool();
+    let mut killer = killer_pool.get().await.unwrap();
+    cmd("CLIENT")
+        .arg("KILL")
+        .arg("ID")
+        .arg(client_id)
+        .query_async::<()>(&mut killer)
+        .await
+        .unwrap();
+
+    // `Fast` doesn't send a `PING` so the dead connection is handed out
+    // again while `Verified` would have replaced it.
+    let mut conn = pool.get().await.unwrap();
+    assert!(cmd("PING").query_async::<String>(&mut conn).await.is_err());
+}
+
+#[tokio::test]
+async fn test_db() {
+    use deadpool_redis::PoolConfig;
+    use redis::aio::ConnectionLike;
+
+    let mut cfg = Config::from_env();
+    cfg.redis.db = Some(1);
+    cfg.redis.pool = Some(PoolConfig::new(1));
+    let pool = cfg.redis.create_pool(Some(Runtime::Tokio1)).unwrap();
+
+    {
+        let mut conn = pool.get().await.unwrap();
+        assert_eq!(conn.get_db(), 1);
+        // Switch to another database before returning the connection
+        cmd("SELECT")
+            .arg(2)
+            .query_async::<()>(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    let mut conn = pool.get().await.unwrap();
+    let client_info = cmd("CLIENT")
+        .arg("INFO")
+        .query_async::<String>(&mut conn)
+        .await
+        .unwrap();
+    assert!(client_info.contains(" db=1 "));
+}
+
+#[tokio::test]
+async fn test_reset_client_tracking() {
+    use deadpool_redis::{ConnectionInfo, ManagerConfig, PoolConfig, ProtocolVersion};
+    use redis::IntoConnectionInfo;
+
+    let mut cfg = Config::from_env();
+    let mut connection_info: ConnectionInfo = cfg
+        .redis
+        .url
+        .take()
+        .unwrap()
+        .into_connection_info()
+        .unwrap()
+        .into();
+    connection_info.redis.protocol = ProtocolVersion::RESP3;
+    cfg.redis.connection = Some(connection_info);
+    cfg.redis.manager = Some(ManagerConfig {
+        reset_client_tracking: true,
+        ..Default::default()
+    });
+    cfg.redis.pool = Some(PoolConfig::new(1));
+    let pool = cfg.redis.create_pool(Some(Runtime::Tokio1)).unwrap();
+
+    {
+        let mut conn = pool.get().await.unwrap();
+        cmd("CLIENT")
+            .arg("TRACKING")
+            .arg("ON")
+            .query_async::<()>(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    // `CLIENT GETREDIR` returns `-1` if tracking is disabled.
+    let mut conn = pool.get().await.unwrap();
+    let redirect = cmd("CLIENT")
+        .arg("GETREDIR")
+        .query_async::<i64>(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(redirect, -1);
+}
+
+#[tokio::test]
+async fn test_response_timeout() {
+    use std::time::Duration;
+
+    use deadpool_redis::ManagerConfig;
+
+    let mut cfg = Config::from_env();
+    cfg.redis.manager = Some(ManagerConfig {
+        response_timeout: Some(Duration::from_millis(100)),
+        ..Default::default()
+    });
+    let pool = cfg.redis.create_pool(Some(Runtime::Tokio1)).unwrap();
+    let mut conn = pool.get().await.unwrap();
+    // Blocks for one second as the list doesn't exist
+    let err = cmd("BLPOP")
+        .arg("deadpool/response_timeout_test_key")
+        .arg(1)
+        .query_async::<Option<(String, String)>>(&mut conn)
+        .await
+        .unwrap_err();
+    assert!(err.is_timeout());
+}
+
+#[tokio::test]
+async fn test_auth_provider() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use deadpool_redis::{AuthProvider, ConnectionInfo, PoolConfig};
+    use redis::IntoConnectionInfo;
+
+    const USERNAME: &str = "deadpool_auth_provider_test";
+
+    {
+        let mut conn = create_pool().get().await.unwrap();
+        cmd("ACL")
+            .arg("SETUSER")
+            .arg(USERNAME)
+            .arg("reset")
+            .arg("on")
+            .arg(">token")
+            .arg("~*")
+            .arg("+@all")
+            .query_async::<()>(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    let mut cfg = Config::from_env();
+    let mut connection: ConnectionInfo = match cfg.redis.url.take() {
+        Some(url) => url.into_connection_info().unwrap().into(),
+        None => cfg.redis.connection.take().unwrap_or_default(),
+    };
+    connection.redis.username = Some(USERNAME.into());
+    connection.redis.password = None;
+    cfg.redis.connection = Some(connection);
+    cfg.redis.pool = Some(PoolConfig::new(2));
+    let calls = Arc::new(AtomicUsize::new(0));
+    cfg.redis.auth_provider = Some(AuthProvider::new({
+        let calls = calls.clone();
+        move || {
+            calls.fetch_add(1, Ordering::Relaxed);
+            "token".into()
+        }
+    }));
+    let pool = cfg.redis.create_pool(Some(Runtime::Tokio1)).unwrap();
+
+    for _ in 0..2 {
+        let mut conn0 = pool.get().await.unwrap();
+        let mut conn1 = pool.get().await.unwrap();
+        for conn in [&mut conn0, &mut conn1] {
+            let username = cmd("ACL")
+                .arg("WHOAMI")
+                .query_async::<String>(conn)
+                .await
+                .unwrap();
+            assert_eq!(username, USERNAME);
+        }
+        // Recycled connections are not authenticated again.
+        assert_eq!(calls.load(Ordering::Relaxed), 2);
+    }
+}
+
+#[tokio::test]
+async fn test_recycled_with_client_tracking() {
+    use deadpool_redis::{ConnectionInfo, PoolConfig, ProtocolVersion};
+    use redis::IntoConnectionInfo;
+
+    const TRACKED_KEY: &str = "deadpool/tracked_test_key";
+
+    let mut cfg = Config::from_env();
+    let mut connection: ConnectionInfo = match cfg.redis.url.take() {
+        Some(url) => url.into_connection_info().unwrap().into(),
+        None => cfg.redis.connection.take().unwrap_or_default(),
+    };
+    connection.redis.protocol = ProtocolVersion::RESP3;
+    cfg.redis.connection = Some(connection);
+    cfg.redis.pool = Some(PoolConfig::new(1));
+    let pool = cfg.redis.create_pool(Some(Runtime::Tokio1)).unwrap();
+
+    {
+        let mut conn = pool.get().await.unwrap();
+        cmd("CLIENT")
+            .arg("TRACKING")
+            .arg("ON")
+            .query_async::<()>(&mut conn)
+            .await
+            .unwrap();
+        cmd("GET")
+            .arg(TRACKED_KEY)
+            .query_async::<Option<String>>(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    {
+        // Modifying the tracked key would cause an invalidation message
+        // to be pushed to the connection if tracking was still enabled.
+        let mut conn = create_pool().get().await.unwrap();
+        cmd("SET")
+            .arg(TRACKED_KEY)
+            .arg("v")
+            .query_async::<()>(&mut conn)
+            .await
+            .unwrap();
+    }
+
+    {
+        let mut conn = pool.get().await.unwrap();
+        let redirect: i64 = cmd("CLIENT")
+            .arg("GETREDIR")
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(redirect, -1, "client tracking was not disabled");
+        let value: String = cmd("PING")
+            .arg("right")
+            .query_async(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(value, "right");
+    }
+}
+
+#[cfg(feature = "helpers")]
+#[tokio::test]
+async fn test_pool_ext() {
+    use deadpool_redis::PoolExt;
+
+    let pool = create_pool();
+    assert_eq!(pool.ping().await.unwrap(), "PONG");
+    pool.cmd::<()>(cmd("SET").arg("deadpool/pool_ext_test_key").arg(42))
+        .await
+        .unwrap();
+    let value: i64 = pool
+        .cmd(cmd("GET").arg("deadpool/pool_ext_test_key"))
+        .await
+        .unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(pool.status().available, 1);
+}
+
+#[cfg(feature = "helpers")]
+#[tokio::test]
+async fn test_mget_chunked() {
+    use deadpool_redis::PoolExt;
+
+    let pool = create_pool();
+    let keys = (0..10)
+        .map(|i| format!("deadpool/mget_chunked_test_key_{}", i))
+        .collect::<Vec<_>>();
+    let mut conn = pool.get().await.unwrap();
+    for (i, key) in keys.iter().enumerate().skip(1) {
+        cmd("SET")
+            .arg(key)
+            .arg(i)
+            .query_async::<()>(&mut conn)
+            .await
+            .unwrap();
+    }
+    cmd("DEL")
+        .arg(&keys[0])
+        .query_async::<()>(&mut conn)
+        .await
+        .unwrap();
+
+    let values: Vec<Option<i64>> = conn.mget_chunked(&keys, 3).await.unwrap();
+    let expected = (0..10)
+        .map(|i| if i == 0 { None } else { Some(i) })
+        .collect::<Vec<_>>();
+    assert_eq!(values, expected);
+    drop(conn);
+
+    let values: Vec<Option<i64>> = pool.mget_chunked(&keys, 4).await.unwrap();
+    assert_eq!(values, expected);
+    let values: Vec<Option<i64>> = pool.mget_chunked(Vec::<String>::new(), 4).await.unwrap();
+    assert!(values.is_empty());
+}
@@ -155,3 +155,35 @@ async fn test_recycled() {
         "The Redis connection was not recycled: different connection name"
     );
 }
+
+#[tokio::test]
+async fn test_recycle_check_cluster_info() {
+    use deadpool_redis::cluster::{PoolConfig, RecycleCheck};
+
+    let mut cfg = Config::from_env();
+    cfg.redis_cluster.recycle_check = RecycleCheck::ClusterInfo;
+    cfg.redis_cluster.pool = Some(PoolConfig::new(1));
+    let pool = cfg
+        .redis_cluster
+        .create_pool(Some(Runtime::Tokio1))
+        .unwrap();
+    drop(pool.get().await.unwrap());
+    drop(pool.get().await.unwrap());
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn test_client_builder_options() {
+    use std::time::Duration;
+
+    let mut cfg = Config::from_env();
+    cfg.redis_cluster.connection_timeout = Some(Duration::from_secs(5));
+    cfg.redis_cluster.retries = Some(2);
+    let pool = cfg
+        .redis_cluster
+        .create_pool(Some(Runtime::Tokio1))
+        .unwrap();
+    let mut conn = pool.get().await.unwrap();
+    let value: String = cmd("PING").query_async(&mut conn).await.unwrap();
+    assert_eq!(value, "PONG");
+}
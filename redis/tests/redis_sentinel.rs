@@ -213,3 +213,31 @@ async fn test_recycled_with_watch() {
         );
     }
 }
+
+#[tokio::test]
+async fn test_verify_master_on_recycle() {
+    let cfg = Config::from_env();
+    assert!(cfg.redis_sentinel.verify_master_on_recycle);
+    let pool = cfg
+        .redis_sentinel
+        .create_pool(Some(Runtime::Tokio1))
+        .unwrap();
+    let role = |mut conn: deadpool_redis::sentinel::Connection| async move {
+        cmd("ROLE")
+            .query_async::<Vec<redis::Value>>(&mut conn)
+            .await
+            .unwrap()
+    };
+    let conn = pool.get().await.unwrap();
+    assert_eq!(
+        role(conn).await[0],
+        redis::Value::BulkString(b"master".to_vec())
+    );
+    // The connection is recycled as the server is still the master
+    let conn = pool.get().await.unwrap();
+    assert_eq!(
+        role(conn).await[0],
+        redis::Value::BulkString(b"master".to_vec())
+    );
+    assert_eq!(pool.status().size, 1);
+}
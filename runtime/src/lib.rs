@@ -21,7 +21,18 @@
 )]
 #![allow(clippy::uninlined_format_args)]
 
-use std::{any::Any, fmt, future::Future, time::Duration};
+use std::{
+    any::Any,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 
 /// Enumeration for picking a runtime implementation.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -31,6 +42,16 @@ pub enum Runtime {
     /// [`tokio` 1.0](tokio_1) runtime.
     Tokio1,
 
+    #[cfg(feature = "tokio_1")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio_1")))]
+    /// [`tokio` 1.0](tokio_1) runtime without a thread pool for blocking
+    /// tasks, e.g. a current-thread runtime on `wasm32` targets.
+    ///
+    /// Closures passed to [`Runtime::spawn_blocking()`] and
+    /// [`Runtime::spawn_blocking_background()`] are run inline blocking the
+    /// current task.
+    Tokio1CurrentThread,
+
     #[cfg(feature = "async-std_1")]
     #[cfg_attr(docsrs, doc(cfg(feature = "async-std_1")))]
     /// [`async-std` 1.0](async_std_1) runtime.
@@ -51,7 +72,9 @@ impl Runtime {
     {
         match self {
             #[cfg(feature = "tokio_1")]
-            Self::Tokio1 => tokio_1::time::timeout(duration, future).await.ok(),
+            Self::Tokio1 | Self::Tokio1CurrentThread => {
+                tokio_1::time::timeout(duration, future).await.ok()
+            }
             #[cfg(feature = "async-std_1")]
             Self::AsyncStd1 => async_std_1::future::timeout(duration, future).await.ok(),
             #[allow(unreachable_patterns)]
@@ -59,6 +82,50 @@ impl Runtime {
         }
     }
 
+    /// Waits until the specified `duration` has elapsed.
+    #[allow(unused_variables)]
+    pub async fn sleep(&self, duration: Duration) {
+        match self {
+            #[cfg(feature = "tokio_1")]
+            Self::Tokio1 | Self::Tokio1CurrentThread => tokio_1::time::sleep(duration).await,
+            #[cfg(feature = "async-std_1")]
+            Self::AsyncStd1 => async_std_1::task::sleep(duration).await,
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+    }
+
+    /// Spawns the given `future` as a background task.
+    ///
+    /// Dropping the returned [`SpawnHandle`] detaches the task. Owners of
+    /// long running tasks (e.g. pools running maintenance loops) should call
+    /// [`SpawnHandle::abort()`] when they are dropped.
+    #[allow(unreachable_code, unused_variables)]
+    pub fn spawn<F>(&self, future: F) -> SpawnHandle
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        let handle = SpawnHandle {
+            state: Arc::new(AbortState {
+                aborted: AtomicBool::new(false),
+                waker: Mutex::new(None),
+            }),
+        };
+        let future = Abortable {
+            future: Box::pin(future),
+            state: handle.state.clone(),
+        };
+        match self {
+            #[cfg(feature = "tokio_1")]
+            Self::Tokio1 | Self::Tokio1CurrentThread => drop(tokio_1::spawn(future)),
+            #[cfg(feature = "async-std_1")]
+            Self::AsyncStd1 => drop(async_std_1::task::spawn(future)),
+            #[allow(unreachable_patterns)]
+            _ => unreachable!(),
+        }
+        handle
+    }
+
     /// Runs the given closure on a thread where blocking is acceptable.
     ///
     /// # Errors
@@ -75,6 +142,9 @@ impl Runtime {
             Self::Tokio1 => tokio_1::task::spawn_blocking(f)
                 .await
                 .map_err(|e| SpawnBlockingError::Panic(e.into_panic())),
+            #[cfg(feature = "tokio_1")]
+            Self::Tokio1CurrentThread => std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+                .map_err(SpawnBlockingError::Panic),
             #[cfg(feature = "async-std_1")]
             Self::AsyncStd1 => Ok(async_std_1::task::spawn_blocking(f).await),
             #[allow(unreachable_patterns)]
@@ -101,6 +171,9 @@ impl Runtime {
                 drop(tokio_1::task::spawn_blocking(f));
                 Ok(())
             }
+            #[cfg(feature = "tokio_1")]
+            Self::Tokio1CurrentThread => std::panic::catch_unwind(std::panic::AssertUnwindSafe(f))
+                .map_err(SpawnBlockingError::Panic),
             #[cfg(feature = "async-std_1")]
             Self::AsyncStd1 => {
                 drop(async_std_1::task::spawn_blocking(f));
@@ -112,6 +185,61 @@ impl Runtime {
     }
 }
 
+/// Handle of a task spawned via [`Runtime::spawn()`].
+///
+/// Dropping this handle detaches the task without canceling it.
+#[derive(Debug)]
+pub struct SpawnHandle {
+    state: Arc<AbortState>,
+}
+
+impl SpawnHandle {
+    /// Aborts the task. The task is canceled the next time it is polled
+    /// by the runtime.
+    pub fn abort(&self) {
+        self.state.aborted.store(true, Ordering::Release);
+        if let Some(waker) = self.state.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+
+    /// Indicates whether [`SpawnHandle::abort()`] has been called.
+    #[must_use]
+    pub fn is_aborted(&self) -> bool {
+        self.state.aborted.load(Ordering::Acquire)
+    }
+}
+
+#[derive(Debug)]
+struct AbortState {
+    aborted: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+/// Wrapper completing the inner future early once its [`SpawnHandle`] has
+/// been aborted.
+struct Abortable<F> {
+    future: Pin<Box<F>>,
+    state: Arc<AbortState>,
+}
+
+impl<F: Future<Output = ()>> Future for Abortable<F> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.state.aborted.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        *self.state.waker.lock().unwrap() = Some(cx.waker().clone());
+        // Check again as the task might have been aborted before the waker
+        // was stored.
+        if self.state.aborted.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+        self.future.as_mut().poll(cx)
+    }
+}
+
 /// Error of spawning a task on a thread where blocking is acceptable.
 #[derive(Debug)]
 pub enum SpawnBlockingError {
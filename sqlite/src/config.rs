@@ -1,4 +1,6 @@
-use std::{convert::Infallible, path::PathBuf};
+use std::{convert::Infallible, path::PathBuf, time::Duration};
+
+use rusqlite::OpenFlags;
 
 use crate::{CreatePoolError, Manager, Pool, PoolBuilder, PoolConfig, Runtime};
 
@@ -34,6 +36,33 @@ pub struct Config {
     /// Path to SQLite database file.
     pub path: PathBuf,
 
+    /// [`OpenFlags`] used for opening the database. This is not supported
+    /// when reading the configuration via `serde`.
+    ///
+    /// Default: [`OpenFlags::default()`]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub open_flags: OpenFlags,
+
+    /// Timeout for waiting on locked tables. See
+    /// [`rusqlite::Connection::busy_timeout()`].
+    ///
+    /// Default: The default of SQLite which doesn't wait at all
+    pub busy_timeout: Option<Duration>,
+
+    /// [`Pragma`]s which are set in the given order after opening a new
+    /// connection.
+    ///
+    /// Default: No pragmas
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pragmas: Vec<Pragma>,
+
+    /// Databases which are attached to every new connection after setting
+    /// the [`pragmas`](Self::pragmas). See [`AttachDatabase`].
+    ///
+    /// Default: No attached databases
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub attach: Vec<AttachDatabase>,
+
     /// [`Pool`] configuration.
     pub pool: Option<PoolConfig>,
 }
@@ -44,10 +73,40 @@ impl Config {
     pub fn new(path: impl Into<PathBuf>) -> Self {
         Self {
             path: path.into(),
+            open_flags: OpenFlags::default(),
+            busy_timeout: None,
+            pragmas: Vec::new(),
+            attach: Vec::new(),
             pool: None,
         }
     }
 
+    /// Create a new [`Config`] for an in-memory database which is shared by
+    /// all connections of the [`Pool`].
+    ///
+    /// Opening `:memory:` creates a separate database for every connection.
+    /// This uses the URI `file:{name}?mode=memory&cache=shared` instead so
+    /// every connection opened with the same `name` within the same process
+    /// accesses the same database. The `name` is percent-encoded so it may
+    /// contain arbitrary characters, including `?`, `#` and `/`.
+    ///
+    /// The database is deleted as soon as its last connection is closed.
+    /// Connections which are removed from the [`Pool`] (e.g. due to a failed
+    /// recycle or by resizing the [`Pool`]) are closed, so make sure that at
+    /// least one connection stays open while the data is still needed.
+    /// Connections of a shared cache database use table level locking, so
+    /// concurrent writes might fail with `SQLITE_LOCKED`.
+    #[must_use]
+    pub fn memory_shared(name: &str) -> Self {
+        Self {
+            open_flags: OpenFlags::default() | OpenFlags::SQLITE_OPEN_URI,
+            ..Self::new(format!(
+                "file:{}?mode=memory&cache=shared",
+                percent_encode(name)
+            ))
+        }
+    }
+
     /// Creates a new [`Pool`] using this [`Config`].
     ///
     /// # Errors
@@ -85,8 +144,72 @@ impl Config {
     }
 }
 
+/// Pragma which is set after opening a new connection, e.g.
+/// `journal_mode = WAL`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct Pragma {
+    /// Name of the pragma.
+    pub name: String,
+
+    /// Value the pragma is set to.
+    pub value: String,
+}
+
+impl Pragma {
+    /// Creates a new [`Pragma`] with the given `name` and `value`.
+    #[must_use]
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Database which is attached after opening a new connection using
+/// `ATTACH DATABASE '{path}' AS {alias}`.
+///
+/// Attached databases are specific to a connection, so this is executed for
+/// every connection of the [`Pool`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AttachDatabase {
+    /// Path of the SQLite database file to attach.
+    pub path: String,
+
+    /// Schema name used for accessing the attached database, e.g.
+    /// `SELECT * FROM {alias}.table`.
+    pub alias: String,
+}
+
+impl AttachDatabase {
+    /// Creates a new [`AttachDatabase`] with the given `path` and `alias`.
+    #[must_use]
+    pub fn new(path: impl Into<String>, alias: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            alias: alias.into(),
+        }
+    }
+}
+
 /// This error is returned if there is something wrong with the SQLite configuration.
 ///
 /// This is just a type alias to [`Infallible`] at the moment as there
 /// is no validation happening at the configuration phase.
 pub type ConfigError = Infallible;
+
+/// Percent-encodes all bytes of the given `value` except for unreserved
+/// characters so it can be used as part of an SQLite URI.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(char::from(byte));
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
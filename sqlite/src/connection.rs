@@ -0,0 +1,49 @@
+use std::future::Future;
+
+use crate::{Connection, InteractError};
+
+/// Extension trait for [`Connection`] providing helpers for running
+/// [`rusqlite`] queries.
+pub trait ConnectionExt {
+    /// Interacts with the underlying [`rusqlite::Connection`] just like
+    /// [`Connection::interact()`] but flattens the errors into a single
+    /// [`InteractError`].
+    ///
+    /// Errors returned by the closure are returned as
+    /// [`InteractError::Backend`] while a panicking closure or an aborted
+    /// connection result in [`InteractError::Panic`] and
+    /// [`InteractError::Aborted`] respectively.
+    ///
+    /// ```rust,no_run
+    /// use deadpool_sqlite::{Config, ConnectionExt, Runtime};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pool = Config::new("db.sqlite3").create_pool(Runtime::Tokio1)?;
+    /// let conn = pool.get().await?;
+    /// let result: i64 = conn
+    ///     .interact_sqlite(|conn| conn.query_row("SELECT 1", [], |row| row.get(0)))
+    ///     .await?;
+    /// assert_eq!(result, 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Connection::interact()`]: deadpool_sync::SyncWrapper::interact
+    fn interact_sqlite<F, R>(
+        &self,
+        f: F,
+    ) -> impl Future<Output = Result<R, InteractError<rusqlite::Error>>> + Send
+    where
+        F: FnOnce(&mut rusqlite::Connection) -> Result<R, rusqlite::Error> + Send + 'static,
+        R: Send + 'static;
+}
+
+impl ConnectionExt for Connection {
+    async fn interact_sqlite<F, R>(&self, f: F) -> Result<R, InteractError<rusqlite::Error>>
+    where
+        F: FnOnce(&mut rusqlite::Connection) -> Result<R, rusqlite::Error> + Send + 'static,
+        R: Send + 'static,
+    {
+        self.interact_backend(f).await
+    }
+}
@@ -22,6 +22,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod config;
+mod connection;
 
 use std::sync::atomic::{AtomicUsize, Ordering};
 
@@ -40,7 +41,10 @@ deadpool::managed_reexports!(
     ConfigError
 );
 
-pub use self::config::{Config, ConfigError};
+pub use self::{
+    config::{AttachDatabase, Config, ConfigError, Pragma},
+    connection::ConnectionExt,
+};
 
 /// Type alias for [`Object`]
 pub type Connection = Object;
@@ -74,7 +78,24 @@ impl managed::Manager for Manager {
 
     async fn create(&self) -> Result<Self::Type, Self::Error> {
         let path = self.config.path.clone();
-        SyncWrapper::new(self.runtime, move || rusqlite::Connection::open(path)).await
+        let open_flags = self.config.open_flags;
+        let busy_timeout = self.config.busy_timeout;
+        let pragmas = self.config.pragmas.clone();
+        let attach = self.config.attach.clone();
+        SyncWrapper::new(self.runtime, move || {
+            let conn = rusqlite::Connection::open_with_flags(path, open_flags)?;
+            if let Some(busy_timeout) = busy_timeout {
+                conn.busy_timeout(busy_timeout)?;
+            }
+            for pragma in &pragmas {
+                conn.pragma_update(None, &pragma.name, &pragma.value)?;
+            }
+            for db in &attach {
+                let _ = conn.execute("ATTACH DATABASE ?1 AS ?2", [&db.path, &db.alias])?;
+            }
+            Ok(conn)
+        })
+        .await
     }
 
     async fn recycle(
@@ -1,9 +1,9 @@
-use deadpool_sqlite::{Config, InteractError, Pool, Runtime};
+use deadpool_sqlite::{rusqlite, Config, ConnectionExt, InteractError, Pool, Runtime};
 
 fn create_pool() -> Pool {
     let cfg = Config {
         path: "db.sqlite3".into(),
-        pool: None,
+        ..Default::default()
     };
     cfg.create_pool(Runtime::Tokio1).unwrap()
 }
@@ -51,3 +51,161 @@ async fn panic() {
         .unwrap();
     assert_eq!(result, 1);
 }
+
+#[tokio::test]
+async fn open_flags() {
+    use rusqlite::OpenFlags;
+
+    let cfg = Config {
+        path: ":memory:".into(),
+        open_flags: OpenFlags::SQLITE_OPEN_READ_ONLY,
+        ..Default::default()
+    };
+    let pool = cfg.create_pool(Runtime::Tokio1).unwrap();
+    let conn = pool.get().await.unwrap();
+    let result = conn
+        .interact(|conn| conn.execute("CREATE TABLE test (id INTEGER)", []))
+        .await
+        .unwrap();
+    assert!(matches!(
+        result,
+        Err(rusqlite::Error::SqliteFailure(e, _)) if e.code == rusqlite::ErrorCode::ReadOnly
+    ));
+}
+
+#[tokio::test]
+async fn busy_timeout() {
+    use std::time::Duration;
+
+    let cfg = Config {
+        path: ":memory:".into(),
+        busy_timeout: Some(Duration::from_millis(1234)),
+        ..Default::default()
+    };
+    let pool = cfg.create_pool(Runtime::Tokio1).unwrap();
+    let conn = pool.get().await.unwrap();
+    let busy_timeout: i64 = conn
+        .interact(|conn| conn.query_row("PRAGMA busy_timeout", [], |row| row.get(0)))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(busy_timeout, 1234);
+}
+
+#[tokio::test]
+async fn pragmas() {
+    use deadpool_sqlite::Pragma;
+
+    let path = std::env::temp_dir().join("deadpool-sqlite-pragmas.sqlite3");
+    let cfg = Config {
+        path,
+        pragmas: vec![
+            Pragma::new("journal_mode", "WAL"),
+            Pragma::new("foreign_keys", "ON"),
+        ],
+        ..Default::default()
+    };
+    let pool = cfg.create_pool(Runtime::Tokio1).unwrap();
+    let conn = pool.get().await.unwrap();
+    let (journal_mode, foreign_keys) = conn
+        .interact(|conn| {
+            let journal_mode: String =
+                conn.query_row("PRAGMA journal_mode", [], |row| row.get(0))?;
+            let foreign_keys: i64 = conn.query_row("PRAGMA foreign_keys", [], |row| row.get(0))?;
+            Ok::<_, rusqlite::Error>((journal_mode, foreign_keys))
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(journal_mode, "wal");
+    assert_eq!(foreign_keys, 1);
+}
+
+#[tokio::test]
+async fn memory_shared() {
+    let pool = Config::memory_shared("deadpool_memory_shared")
+        .create_pool(Runtime::Tokio1)
+        .unwrap();
+    let writer = pool.get().await.unwrap();
+    let reader = pool.get().await.unwrap();
+    writer
+        .interact(|conn| {
+            conn.execute_batch("CREATE TABLE t (x INTEGER); INSERT INTO t VALUES (42);")
+        })
+        .await
+        .unwrap()
+        .unwrap();
+    let x: i64 = reader
+        .interact(|conn| conn.query_row("SELECT x FROM t", [], |row| row.get(0)))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(x, 42);
+}
+
+#[tokio::test]
+async fn memory_shared_special_characters() {
+    // Without encoding this would be parsed as the file `deadpool/shared`
+    // in a directory which doesn't exist.
+    let pool = Config::memory_shared("deadpool/shared?mode=rwc#1")
+        .create_pool(Runtime::Tokio1)
+        .unwrap();
+    let writer = pool.get().await.unwrap();
+    let reader = pool.get().await.unwrap();
+    writer
+        .interact(|conn| conn.execute_batch("CREATE TABLE t (x INTEGER)"))
+        .await
+        .unwrap()
+        .unwrap();
+    let count: i64 = reader
+        .interact(|conn| conn.query_row("SELECT COUNT(*) FROM t", [], |row| row.get(0)))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[tokio::test]
+async fn interact_sqlite() {
+    let pool = create_pool();
+    let conn = pool.get().await.unwrap();
+    let result: i64 = conn
+        .interact_sqlite(|conn| conn.query_row("SELECT 1", [], |row| row.get(0)))
+        .await
+        .unwrap();
+    assert_eq!(result, 1);
+    let result = conn
+        .interact_sqlite(|conn| conn.execute("INVALID SQL", []))
+        .await;
+    assert!(matches!(
+        result,
+        Err(InteractError::Backend(rusqlite::Error::SqliteFailure(..)))
+    ));
+}
+
+#[tokio::test]
+async fn attach() {
+    use deadpool_sqlite::AttachDatabase;
+
+    let other = std::env::temp_dir().join("deadpool-sqlite-attach.sqlite3");
+    {
+        let conn = rusqlite::Connection::open(&other).unwrap();
+        conn.execute_batch(
+            "DROP TABLE IF EXISTS t; CREATE TABLE t (x INTEGER); INSERT INTO t VALUES (42);",
+        )
+        .unwrap();
+    }
+    let cfg = Config {
+        path: ":memory:".into(),
+        attach: vec![AttachDatabase::new(other.to_str().unwrap(), "other")],
+        ..Default::default()
+    };
+    let pool = cfg.create_pool(Runtime::Tokio1).unwrap();
+    let conn = pool.get().await.unwrap();
+    let x: i64 = conn
+        .interact(|conn| conn.query_row("SELECT x FROM other.t", [], |row| row.get(0)))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(x, 42);
+}
@@ -21,6 +21,9 @@
 )]
 #![allow(clippy::uninlined_format_args)]
 
+#[cfg(any(feature = "managed", feature = "unmanaged"))]
+mod dropguard;
+
 #[cfg(feature = "managed")]
 #[cfg_attr(docsrs, doc(cfg(feature = "managed")))]
 pub mod managed;
@@ -29,7 +32,7 @@ pub mod managed;
 #[cfg_attr(docsrs, doc(cfg(feature = "unmanaged")))]
 pub mod unmanaged;
 
-pub use deadpool_runtime::{Runtime, SpawnBlockingError};
+pub use deadpool_runtime::{Runtime, SpawnBlockingError, SpawnHandle};
 
 /// The current pool status.
 ///
@@ -1,17 +1,20 @@
-use std::{fmt, marker::PhantomData, time::Duration};
+use std::{fmt, marker::PhantomData, sync::Arc, time::Duration};
+
+use tokio::sync::Semaphore;
 
 use crate::Runtime;
 
 use super::{
-    hooks::{Hook, Hooks},
-    Manager, Object, Pool, PoolConfig, QueueMode, Timeouts,
+    hooks::{Hook, Hooks, PreCreateHook},
+    Manager, Object, Pool, PoolConfig, PoolObserver, QueueMode, RetryConfig, Timeouts, WaiterMode,
 };
 
 /// Possible errors returned when [`PoolBuilder::build()`] fails to build a
 /// [`Pool`].
 #[derive(Copy, Clone, Debug)]
 pub enum BuildError {
-    /// [`Runtime`] is required du to configured timeouts.
+    /// [`Runtime`] is required du to configured timeouts,
+    /// [`PoolConfig::min_idle`] or [`PoolConfig::create_retry`].
     NoRuntimeSpecified,
 }
 
@@ -20,7 +23,7 @@ impl fmt::Display for BuildError {
         match self {
             Self::NoRuntimeSpecified => write!(
                 f,
-                "Error occurred while building the pool: Timeouts require a runtime",
+                "Error occurred while building the pool: Timeouts, min_idle and create_retry require a runtime",
             ),
         }
     }
@@ -47,6 +50,8 @@ where
     pub(crate) config: PoolConfig,
     pub(crate) runtime: Option<Runtime>,
     pub(crate) hooks: Hooks<M>,
+    pub(crate) observer: Option<Arc<dyn PoolObserver<M>>>,
+    pub(crate) shared_limit: Option<Arc<Semaphore>>,
     _wrapper: PhantomData<fn() -> W>,
 }
 
@@ -62,6 +67,8 @@ where
             .field("config", &self.config)
             .field("runtime", &self.runtime)
             .field("hooks", &self.hooks)
+            .field("observer", &self.observer.as_ref().map(|_| "PoolObserver"))
+            .field("shared_limit", &self.shared_limit)
             .field("_wrapper", &self._wrapper)
             .finish()
     }
@@ -78,23 +85,42 @@ where
             config: PoolConfig::default(),
             runtime: None,
             hooks: Hooks::default(),
+            observer: None,
+            shared_limit: None,
             _wrapper: PhantomData,
         }
     }
 
     /// Builds the [`Pool`].
     ///
+    /// If [`PoolConfig::min_idle`] is set, this spawns a background task
+    /// filling the [`Pool`] using the configured [`Runtime`], so it must be
+    /// called from within the context of that [`Runtime`].
+    ///
     /// # Errors
     ///
     /// See [`BuildError`] for details.
-    pub fn build(self) -> Result<Pool<M, W>, BuildError> {
+    pub fn build(self) -> Result<Pool<M, W>, BuildError>
+    where
+        M: 'static,
+    {
         // Return an error if a timeout is configured without runtime.
         let t = &self.config.timeouts;
         if (t.wait.is_some() || t.create.is_some() || t.recycle.is_some()) && self.runtime.is_none()
         {
             return Err(BuildError::NoRuntimeSpecified);
         }
-        Ok(Pool::from_builder(self))
+        // Keeping a minimum number of objects requires a background task.
+        if self.config.min_idle.is_some() && self.runtime.is_none() {
+            return Err(BuildError::NoRuntimeSpecified);
+        }
+        // Waiting between retries requires a timer.
+        if self.config.create_retry.is_some() && self.runtime.is_none() {
+            return Err(BuildError::NoRuntimeSpecified);
+        }
+        let pool = Pool::from_builder(self);
+        pool.spawn_min_idle_task();
+        Ok(pool)
     }
 
     /// Sets a [`PoolConfig`] to build the [`Pool`] with.
@@ -104,6 +130,9 @@ where
     }
 
     /// Sets the [`PoolConfig::max_size`].
+    ///
+    /// See [`PoolConfig::max_size`] for the behavior of a [`Pool`] built
+    /// with a `max_size` of `0`.
     pub fn max_size(mut self, value: usize) -> Self {
         self.config.max_size = value;
         self
@@ -139,6 +168,48 @@ where
         self
     }
 
+    /// Sets the [`PoolConfig::waiter_mode`].
+    pub fn waiter_mode(mut self, value: WaiterMode) -> Self {
+        self.config.waiter_mode = value;
+        self
+    }
+
+    /// Sets the [`PoolConfig::max_lifetime`].
+    pub fn max_lifetime(mut self, value: Option<Duration>) -> Self {
+        self.config.max_lifetime = value;
+        self
+    }
+
+    /// Sets the [`PoolConfig::idle_timeout`].
+    pub fn idle_timeout(mut self, value: Option<Duration>) -> Self {
+        self.config.idle_timeout = value;
+        self
+    }
+
+    /// Sets the [`PoolConfig::min_idle`].
+    pub fn min_idle(mut self, value: Option<usize>) -> Self {
+        self.config.min_idle = value;
+        self
+    }
+
+    /// Sets the [`PoolConfig::create_retry`] policy.
+    pub fn create_retry(mut self, value: RetryConfig) -> Self {
+        self.config.create_retry = Some(value);
+        self
+    }
+
+    /// Attaches a `pre_create` hook.
+    ///
+    /// The given `hook` will be called each time right before a new [`Object`]
+    /// will be created. If the `hook` returns an error no [`Object`] is
+    /// created and [`PoolError::PreCreateHook`] is returned.
+    ///
+    /// [`PoolError::PreCreateHook`]: super::PoolError::PreCreateHook
+    pub fn pre_create(mut self, hook: impl Into<PreCreateHook<M>>) -> Self {
+        self.hooks.pre_create.push(hook.into());
+        self
+    }
+
     /// Attaches a `post_create` hook.
     ///
     /// The given `hook` will be called each time right after a new [`Object`]
@@ -166,6 +237,37 @@ where
         self
     }
 
+    /// Sets the [`PoolObserver`].
+    ///
+    /// The given `observer` will be notified about objects being created,
+    /// recycled, detached and handed out as well as timeouts occurring.
+    pub fn observer(mut self, observer: Arc<dyn PoolObserver<M>>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Sets a [`Semaphore`] limiting the number of [`Object`]s which can be
+    /// borrowed at the same time across multiple [`Pool`]s.
+    ///
+    /// Every [`Object`] holds a permit of the given `semaphore` in addition
+    /// to its slot of the [`Pool`] until it is returned or taken. This makes
+    /// it possible to limit the total number of connections of many pools
+    /// (e.g. one per tenant) while every [`Pool`] is still limited by its
+    /// own `max_size`.
+    ///
+    /// [`Pool::get()`] first waits for a slot of the [`Pool`] and only then
+    /// for a permit of the shared `semaphore`. Both count towards the wait
+    /// timeout. As a permit of the shared `semaphore` is never held while
+    /// waiting for a slot, tasks waiting for different pools can't deadlock
+    /// each other. Closing the `semaphore` makes [`Pool::get()`] return
+    /// [`PoolError::Closed`].
+    ///
+    /// [`PoolError::Closed`]: super::PoolError::Closed
+    pub fn shared_limit(mut self, semaphore: Arc<Semaphore>) -> Self {
+        self.shared_limit = Some(semaphore);
+        self
+    }
+
     /// Sets the [`Runtime`].
     ///
     /// # Important
@@ -10,9 +10,16 @@ use super::BuildError;
 pub struct PoolConfig {
     /// Maximum size of the [`Pool`].
     ///
+    /// A `max_size` of `0` is allowed and creates a [`Pool`] which doesn't
+    /// hand out any objects until it is grown using [`Pool::resize()`].
+    /// Until then [`Pool::get()`] waits for the configured
+    /// [`Timeouts::wait`] or forever if none is set.
+    ///
     /// Default: `cpu_count * 4`
     ///
     /// [`Pool`]: super::Pool
+    /// [`Pool::resize()`]: super::Pool::resize
+    /// [`Pool::get()`]: super::Pool::get
     pub max_size: usize,
 
     /// Timeouts of the [`Pool`].
@@ -32,6 +39,73 @@ pub struct PoolConfig {
     /// [`Pool`]: super::Pool
     #[cfg_attr(feature = "serde", serde(default))]
     pub queue_mode: QueueMode,
+
+    /// Waiter mode of the [`Pool`].
+    ///
+    /// Determines the order in which tasks waiting for an [`Object`] are
+    /// served.
+    ///
+    /// Default: `Fifo`
+    ///
+    /// [`Object`]: super::Object
+    /// [`Pool`]: super::Pool
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub waiter_mode: WaiterMode,
+
+    /// Maximum lifetime of [`Object`]s.
+    ///
+    /// [`Object`]s which are older than this are discarded instead of being
+    /// recycled.
+    ///
+    /// Default: No maximum lifetime
+    ///
+    /// [`Object`]: super::Object
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_lifetime: Option<Duration>,
+
+    /// Maximum idle time of [`Object`]s.
+    ///
+    /// [`Object`]s which haven't been used for longer than this (see
+    /// [`Metrics::last_used()`]) are discarded instead of being recycled.
+    ///
+    /// Default: No idle timeout
+    ///
+    /// [`Metrics::last_used()`]: super::Metrics::last_used
+    /// [`Object`]: super::Object
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub idle_timeout: Option<Duration>,
+
+    /// Minimum number of [`Object`]s the [`Pool`] tries to keep around.
+    ///
+    /// [`PoolBuilder::build()`] starts a background task which fills the
+    /// [`Pool`] up to this size and replenishes it whenever [`Object`]s are
+    /// removed from it. This requires a [`Runtime`] and
+    /// [`PoolBuilder::build()`] must be called from within its context.
+    /// [`Pool::warmup()`] can be used for waiting until the [`Pool`] is
+    /// filled.
+    ///
+    /// Default: No minimum
+    ///
+    /// [`Object`]: super::Object
+    /// [`Pool`]: super::Pool
+    /// [`Pool::warmup()`]: super::Pool::warmup
+    /// [`PoolBuilder::build()`]: super::PoolBuilder::build
+    /// [`Runtime`]: crate::Runtime
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub min_idle: Option<usize>,
+
+    /// Retry policy for creating new [`Object`]s.
+    ///
+    /// If set, failed attempts to create a new [`Object`] are retried with
+    /// an exponential backoff. All attempts share the [`Timeouts::create`]
+    /// timeout. This requires a [`Runtime`].
+    ///
+    /// Default: No retries
+    ///
+    /// [`Object`]: super::Object
+    /// [`Runtime`]: crate::Runtime
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub create_retry: Option<RetryConfig>,
 }
 
 impl PoolConfig {
@@ -43,6 +117,11 @@ impl PoolConfig {
             max_size,
             timeouts: Timeouts::default(),
             queue_mode: QueueMode::default(),
+            waiter_mode: WaiterMode::default(),
+            max_lifetime: None,
+            idle_timeout: None,
+            min_idle: None,
+            create_retry: None,
         }
     }
 }
@@ -55,20 +134,61 @@ impl Default for PoolConfig {
     }
 }
 
+/// Options for retrieving a single [`Object`] via [`Pool::get_with()`].
+///
+/// Objects are always checked against [`PoolConfig::max_lifetime`] and
+/// [`PoolConfig::idle_timeout`] and new objects are always passed to the
+/// `post_create` hooks.
+///
+/// [`Object`]: super::Object
+/// [`Pool::get_with()`]: super::Pool::get_with
+#[derive(Clone, Copy, Debug, Default)]
+pub struct GetOptions {
+    /// Skip the `pre_recycle` and `post_recycle` hooks when reusing an
+    /// existing [`Object`].
+    ///
+    /// [`Object`]: super::Object
+    pub skip_hooks: bool,
+
+    /// Skip [`Manager::recycle()`] when reusing an existing [`Object`]. The
+    /// [`Metrics`] of the [`Object`] are not updated in this case.
+    ///
+    /// [`Manager::recycle()`]: super::Manager::recycle
+    /// [`Metrics`]: super::Metrics
+    /// [`Object`]: super::Object
+    pub skip_recycle: bool,
+}
+
 /// Timeouts when getting [`Object`]s from a [`Pool`].
 ///
+/// When deserialized each timeout can either be given as a number of
+/// seconds (e.g. `5` or `0.5`) or in the `secs`/`nanos` form used by
+/// [`Duration`].
+///
 /// [`Object`]: super::Object
 /// [`Pool`]: super::Pool
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Timeouts {
     /// Timeout when waiting for a slot to become available.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "serde_duration::deserialize")
+    )]
     pub wait: Option<Duration>,
 
     /// Timeout when creating a new object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "serde_duration::deserialize")
+    )]
     pub create: Option<Duration>,
 
     /// Timeout when recycling an object.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "serde_duration::deserialize")
+    )]
     pub recycle: Option<Duration>,
 }
 
@@ -103,17 +223,113 @@ impl Default for Timeouts {
     }
 }
 
-/// Mode for dequeuing [`Object`]s from a [`Pool`].
+/// Retry policy used when creating new [`Object`]s fails.
+///
+/// The delay between two attempts starts at `base_delay` and is doubled
+/// after every failed attempt without exceeding `max_delay`.
+///
+/// [`Object`]: super::Object
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RetryConfig {
+    /// Maximum number of retries after the first failed attempt.
+    pub max_retries: usize,
+
+    /// Delay before the first retry.
+    pub base_delay: Duration,
+
+    /// Maximum delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl RetryConfig {
+    /// Returns the delay before the given retry `attempt` (starting at 0).
+    pub(crate) fn delay(&self, attempt: usize) -> Duration {
+        let factor = u32::try_from(attempt)
+            .ok()
+            .and_then(|attempt| 2u32.checked_pow(attempt))
+            .unwrap_or(u32::MAX);
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+}
+
+/// Configuration of the background task started by
+/// [`Pool::spawn_maintenance()`].
+///
+/// Every `interval` idle [`Object`]s exceeding `max_idle` or `max_lifetime`
+/// are removed from the [`Pool`] using [`Pool::retain()`].
 ///
 /// [`Object`]: super::Object
 /// [`Pool`]: super::Pool
+/// [`Pool::retain()`]: super::Pool::retain
+/// [`Pool::spawn_maintenance()`]: super::Pool::spawn_maintenance
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct MaintenanceConfig {
+    /// Time between two runs of the maintenance task.
+    pub interval: Duration,
+
+    /// Maximum time an [`Object`] may stay idle. See
+    /// [`Metrics::last_used()`].
+    ///
+    /// Default: No limit
+    ///
+    /// [`Metrics::last_used()`]: super::Metrics::last_used
+    /// [`Object`]: super::Object
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_idle: Option<Duration>,
+
+    /// Maximum age of an [`Object`]. See [`Metrics::age()`].
+    ///
+    /// Default: No limit
+    ///
+    /// [`Metrics::age()`]: super::Metrics::age
+    /// [`Object`]: super::Object
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub max_lifetime: Option<Duration>,
+}
+
+impl MaintenanceConfig {
+    /// Creates a new [`MaintenanceConfig`] running every `interval` without
+    /// any limits.
+    #[must_use]
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            max_idle: None,
+            max_lifetime: None,
+        }
+    }
+}
+
+/// Mode for dequeuing [`Object`]s from a [`Pool`].
+///
+/// When deserialized the name of the variant is matched case-insensitively
+/// (e.g. `"fifo"` or `"LIFO"`).
+///
+/// [`Object`]: super::Object
+/// [`Pool`]: super::Pool
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum QueueMode {
     /// Dequeue the object that was least recently added (first in first out).
     Fifo,
     /// Dequeue the object that was most recently added (last in first out).
     Lifo,
+    /// Dequeue the object that was most recently used according to its
+    /// [`Metrics`] (i.e. the one most recently created or recycled).
+    ///
+    /// This keeps a small set of objects in use while the others age. As
+    /// those are rarely dequeued they should be removed periodically via
+    /// [`Pool::retain()`] (e.g. by checking [`Metrics::last_used()`]) which
+    /// shrinks the [`Pool`] during low traffic. Finding the object requires
+    /// scanning all idle objects.
+    ///
+    /// [`Metrics`]: super::Metrics
+    /// [`Metrics::last_used()`]: super::Metrics::last_used
+    /// [`Pool`]: super::Pool
+    /// [`Pool::retain()`]: super::Pool::retain
+    LruByMetrics,
 }
 
 impl Default for QueueMode {
@@ -122,6 +338,40 @@ impl Default for QueueMode {
     }
 }
 
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for QueueMode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        const VARIANTS: &[&str] = &["Fifo", "Lifo", "LruByMetrics"];
+        let name = String::deserialize(deserializer)?;
+        match name.to_ascii_lowercase().as_str() {
+            "fifo" => Ok(Self::Fifo),
+            "lifo" => Ok(Self::Lifo),
+            "lrubymetrics" => Ok(Self::LruByMetrics),
+            _ => Err(serde::de::Error::unknown_variant(&name, VARIANTS)),
+        }
+    }
+}
+
+/// Mode for serving tasks waiting for [`Object`]s of a [`Pool`].
+///
+/// `Fifo` is fair as every waiting task is eventually served in the order
+/// it started waiting. `Lifo` serves the most recent waiter first which
+/// keeps the latency low for most tasks when the [`Pool`] is only
+/// temporarily exhausted (e.g. during bursts). Under sustained load,
+/// however, old waiters can starve until they hit their wait timeout.
+///
+/// [`Object`]: super::Object
+/// [`Pool`]: super::Pool
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WaiterMode {
+    /// Serve the task that has been waiting the longest (first in first out).
+    #[default]
+    Fifo,
+    /// Serve the task that started waiting most recently (last in first out).
+    Lifo,
+}
+
 /// This error is used when building pools via the config `create_pool`
 /// methods.
 #[derive(Debug)]
@@ -145,3 +395,81 @@ where
 }
 
 impl<C> std::error::Error for CreatePoolError<C> where C: std::error::Error {}
+
+/// Deserialization of [`Timeouts`] accepting plain numbers of seconds in
+/// addition to the `secs`/`nanos` form of [`Duration`].
+#[cfg(feature = "serde")]
+mod serde_duration {
+    use std::{fmt, time::Duration};
+
+    use serde::de::{self, value::MapAccessDeserializer, Deserialize, Deserializer, Visitor};
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<Duration>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an optional duration")
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(
+            self,
+            deserializer: D,
+        ) -> Result<Self::Value, D::Error> {
+            deserializer.deserialize_any(DurationVisitor).map(Some)
+        }
+    }
+
+    struct DurationVisitor;
+
+    impl<'de> Visitor<'de> for DurationVisitor {
+        type Value = Duration;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a number of seconds or a struct with `secs` and `nanos`")
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+            Ok(Duration::from_secs(v))
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+            u64::try_from(v)
+                .map(Duration::from_secs)
+                .map_err(|_| E::invalid_value(de::Unexpected::Signed(v), &self))
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+            Duration::try_from_secs_f64(v)
+                .map_err(|_| E::invalid_value(de::Unexpected::Float(v), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+            // Values from environment variables are always strings.
+            match v.trim().parse::<f64>() {
+                Ok(secs) => self.visit_f64(secs),
+                Err(_) => Err(E::invalid_value(de::Unexpected::Str(v), &self)),
+            }
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Duration, A::Error> {
+            Duration::deserialize(MapAccessDeserializer::new(map))
+        }
+    }
+}
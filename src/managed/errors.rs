@@ -1,4 +1,4 @@
-use std::{borrow::Cow, fmt};
+use std::{any::Any, borrow::Cow, fmt};
 
 use super::hooks::HookError;
 
@@ -46,6 +46,37 @@ impl<E: std::error::Error + 'static> std::error::Error for RecycleError<E> {
     }
 }
 
+/// Possible errors occurring while creating a new object.
+pub(crate) enum CreateError<E> {
+    /// Error caused by the backend.
+    Backend(E),
+
+    /// [`Manager::create()`] has panicked.
+    ///
+    /// [`Manager::create()`]: super::Manager::create
+    Panic(Box<dyn Any + Send + 'static>),
+}
+
+impl<E> From<CreateError<E>> for PoolError<E> {
+    fn from(e: CreateError<E>) -> Self {
+        match e {
+            CreateError::Backend(e) => Self::Backend(e),
+            CreateError::Panic(payload) => Self::CreatePanic(panic_message(payload.as_ref())),
+        }
+    }
+}
+
+/// Extracts the message of a panic payload.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&'static str>() {
+        (*msg).to_owned()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "Box<dyn Any>".to_owned()
+    }
+}
+
 /// Possible steps causing the timeout in an error returned by [`Pool::get()`]
 /// method.
 ///
@@ -70,7 +101,18 @@ pub enum PoolError<E> {
     /// Timeout happened.
     Timeout(TimeoutType),
 
-    /// Backend reported an error.
+    /// Backend reported an error while creating a new object.
+    ///
+    /// This error always originates from [`Manager::create()`]. Objects
+    /// failing to recycle are discarded and [`Pool::get()`] continues with
+    /// the next available object or creates a new one, so errors returned by
+    /// [`Manager::recycle()`] are never returned to the caller. Retrying
+    /// therefore only makes sense if the cause of the failed creation (e.g.
+    /// an unreachable server) is expected to go away.
+    ///
+    /// [`Manager::create()`]: super::Manager::create
+    /// [`Manager::recycle()`]: super::Manager::recycle
+    /// [`Pool::get()`]: super::Pool::get
     Backend(E),
 
     /// [`Pool`] has been closed.
@@ -83,8 +125,16 @@ pub enum PoolError<E> {
     /// [`Runtime`]: crate::Runtime
     NoRuntimeSpecified,
 
+    /// A `pre_create` hook reported an error.
+    PreCreateHook(HookError<E>),
+
     /// A `post_create` hook reported an error.
     PostCreateHook(HookError<E>),
+
+    /// [`Manager::create()`] has panicked. Contains the panic message.
+    ///
+    /// [`Manager::create()`]: super::Manager::create
+    CreatePanic(String),
 }
 
 impl<E> From<E> for PoolError<E> {
@@ -107,7 +157,11 @@ impl<E: fmt::Display> fmt::Display for PoolError<E> {
             Self::Backend(e) => write!(f, "Error occurred while creating a new object: {}", e),
             Self::Closed => write!(f, "Pool has been closed"),
             Self::NoRuntimeSpecified => write!(f, "No runtime specified"),
+            Self::PreCreateHook(e) => write!(f, "`pre_create` hook failed: {}", e),
             Self::PostCreateHook(e) => writeln!(f, "`post_create` hook failed: {}", e),
+            Self::CreatePanic(msg) => {
+                write!(f, "Panic occurred while creating a new object: {}", msg)
+            }
         }
     }
 }
@@ -115,9 +169,11 @@ impl<E: fmt::Display> fmt::Display for PoolError<E> {
 impl<E: std::error::Error + 'static> std::error::Error for PoolError<E> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::Timeout(_) | Self::Closed | Self::NoRuntimeSpecified => None,
+            Self::Timeout(_) | Self::Closed | Self::NoRuntimeSpecified | Self::CreatePanic(_) => {
+                None
+            }
             Self::Backend(e) => Some(e),
-            Self::PostCreateHook(e) => Some(e),
+            Self::PreCreateHook(e) | Self::PostCreateHook(e) => Some(e),
         }
     }
 }
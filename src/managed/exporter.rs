@@ -0,0 +1,60 @@
+//! Integration with the [`metrics`] crate.
+
+use metrics::{counter, gauge, Counter, Gauge};
+
+use super::{Status, TimeoutType};
+
+/// Handles of the metrics registered by [`Pool::register_metrics()`].
+///
+/// [`Pool::register_metrics()`]: super::Pool::register_metrics
+pub(crate) struct MetricsExporter {
+    max_size: Gauge,
+    size: Gauge,
+    available: Gauge,
+    waiting: Gauge,
+    pub(crate) created: Counter,
+    pub(crate) recycled: Counter,
+    pub(crate) detached: Counter,
+    timeouts_wait: Counter,
+    timeouts_create: Counter,
+    timeouts_recycle: Counter,
+}
+
+impl MetricsExporter {
+    /// Registers all metrics using the given `namespace` as prefix with the
+    /// currently installed [`metrics::Recorder`].
+    pub(crate) fn new(namespace: &str) -> Self {
+        let name = |suffix: &str| format!("{}_{}", namespace, suffix);
+        let timeouts = name("pool_timeouts_total");
+        Self {
+            max_size: gauge!(name("pool_max_size")),
+            size: gauge!(name("pool_size")),
+            available: gauge!(name("pool_available")),
+            waiting: gauge!(name("pool_waiting")),
+            created: counter!(name("pool_objects_created_total")),
+            recycled: counter!(name("pool_objects_recycled_total")),
+            detached: counter!(name("pool_objects_detached_total")),
+            timeouts_wait: counter!(timeouts.clone(), "type" => "wait"),
+            timeouts_create: counter!(timeouts.clone(), "type" => "create"),
+            timeouts_recycle: counter!(timeouts, "type" => "recycle"),
+        }
+    }
+
+    /// Updates the gauges using the given [`Status`].
+    pub(crate) fn set_status(&self, status: Status) {
+        self.max_size.set(status.max_size as f64);
+        self.size.set(status.size as f64);
+        self.available.set(status.available as f64);
+        self.waiting.set(status.waiting as f64);
+    }
+
+    /// Increments the counter of the given [`TimeoutType`].
+    pub(crate) fn on_timeout(&self, timeout_type: TimeoutType) {
+        let counter = match timeout_type {
+            TimeoutType::Wait => &self.timeouts_wait,
+            TimeoutType::Create => &self.timeouts_create,
+            TimeoutType::Recycle => &self.timeouts_recycle,
+        };
+        counter.increment(1);
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt,
+};
+
+type AnyMap = HashMap<TypeId, Box<dyn Any + Send + Sync>>;
+
+/// Type map for attaching arbitrary data to an [`Object`].
+///
+/// Extensions are stored alongside the [`Metrics`] of an [`Object`] and
+/// survive recycling. No memory is allocated until the first value is
+/// inserted.
+///
+/// [`Metrics`]: super::Metrics
+/// [`Object`]: super::Object
+#[derive(Default)]
+pub struct Extensions {
+    map: Option<Box<AnyMap>>,
+}
+
+impl Extensions {
+    /// Creates an empty [`Extensions`] map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value into this map replacing and returning the previous
+    /// value of the same type.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) -> Option<T> {
+        self.map
+            .get_or_insert_with(Box::default)
+            .insert(TypeId::of::<T>(), Box::new(value))
+            .and_then(|prev| prev.downcast().ok().map(|prev| *prev))
+    }
+
+    /// Returns a reference to the value of type `T` if it exists.
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<&T> {
+        self.map
+            .as_ref()
+            .and_then(|map| map.get(&TypeId::of::<T>()))
+            .and_then(|value| value.downcast_ref())
+    }
+
+    /// Returns a mutable reference to the value of type `T` if it exists.
+    pub fn get_mut<T: Send + Sync + 'static>(&mut self) -> Option<&mut T> {
+        self.map
+            .as_mut()
+            .and_then(|map| map.get_mut(&TypeId::of::<T>()))
+            .and_then(|value| value.downcast_mut())
+    }
+
+    /// Removes the value of type `T` from this map and returns it.
+    pub fn remove<T: Send + Sync + 'static>(&mut self) -> Option<T> {
+        self.map
+            .as_mut()
+            .and_then(|map| map.remove(&TypeId::of::<T>()))
+            .and_then(|value| value.downcast().ok().map(|value| *value))
+    }
+
+    /// Removes all values from this map.
+    pub fn clear(&mut self) {
+        if let Some(map) = &mut self.map {
+            map.clear();
+        }
+    }
+
+    /// Indicates whether this map is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.map.as_ref().map_or(true, |map| map.is_empty())
+    }
+
+    /// Returns the number of values in this map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.map.as_ref().map_or(0, |map| map.len())
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Extensions")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -10,6 +10,15 @@ pub type HookResult<E> = Result<(), HookError<E>>;
 /// The boxed future that should be returned by async hooks
 pub type HookFuture<'a, E> = Pin<Box<dyn Future<Output = HookResult<E>> + Send + 'a>>;
 
+/// The result returned by hooks replacing the object. `Some` contains the
+/// object replacing the current one while `None` keeps the current object.
+pub type ReplaceHookResult<T, E> = Result<Option<T>, HookError<E>>;
+
+/// The boxed future that should be returned by async hooks replacing the
+/// object
+pub type ReplaceHookFuture<'a, T, E> =
+    Pin<Box<dyn Future<Output = ReplaceHookResult<T, E>> + Send + 'a>>;
+
 /// Function signature for sync callbacks
 type SyncFn<M> =
     dyn Fn(&mut <M as Manager>::Type, &Metrics) -> HookResult<<M as Manager>::Error> + Sync + Send;
@@ -19,12 +28,33 @@ type AsyncFn<M> = dyn for<'a> Fn(&'a mut <M as Manager>::Type, &'a Metrics) -> H
     + Sync
     + Send;
 
+/// Function signature for sync callbacks replacing the object
+type SyncReplaceFn<M> = dyn Fn(
+        &mut <M as Manager>::Type,
+        &Metrics,
+    ) -> ReplaceHookResult<<M as Manager>::Type, <M as Manager>::Error>
+    + Sync
+    + Send;
+
+/// Function signature for async callbacks replacing the object
+type AsyncReplaceFn<M> = dyn for<'a> Fn(
+        &'a mut <M as Manager>::Type,
+        &'a Metrics,
+    ) -> ReplaceHookFuture<'a, <M as Manager>::Type, <M as Manager>::Error>
+    + Sync
+    + Send;
+
 /// Wrapper for hook functions
 pub enum Hook<M: Manager> {
     /// Use a plain function (non-async) as a hook
     Fn(Box<SyncFn<M>>),
     /// Use an async function as a hook
     AsyncFn(Box<AsyncFn<M>>),
+    /// Use a plain function (non-async) which can replace the object as a
+    /// hook
+    ReplaceFn(Box<SyncReplaceFn<M>>),
+    /// Use an async function which can replace the object as a hook
+    AsyncReplaceFn(Box<AsyncReplaceFn<M>>),
 }
 
 impl<M: Manager> Hook<M> {
@@ -43,6 +73,31 @@ impl<M: Manager> Hook<M> {
     ) -> Self {
         Self::AsyncFn(Box::new(f))
     }
+    /// Create Hook from sync function which can replace the object
+    ///
+    /// If the function returns `Some` the object is replaced by the returned
+    /// one and the previous object is passed to [`Manager::detach()`] before
+    /// it is dropped. Returning `None` keeps the object. This works for all
+    /// hooks, i.e. objects can also be replaced when being recycled.
+    pub fn sync_replace_fn(
+        f: impl Fn(&mut M::Type, &Metrics) -> ReplaceHookResult<M::Type, M::Error>
+            + Sync
+            + Send
+            + 'static,
+    ) -> Self {
+        Self::ReplaceFn(Box::new(f))
+    }
+    /// Create Hook from async function which can replace the object
+    ///
+    /// See [`Hook::sync_replace_fn()`] for details.
+    pub fn async_replace_fn(
+        f: impl for<'a> Fn(&'a mut M::Type, &'a Metrics) -> ReplaceHookFuture<'a, M::Type, M::Error>
+            + Sync
+            + Send
+            + 'static,
+    ) -> Self {
+        Self::AsyncReplaceFn(Box::new(f))
+    }
 }
 
 impl<M: Manager> fmt::Debug for Hook<M> {
@@ -56,6 +111,48 @@ impl<M: Manager> fmt::Debug for Hook<M> {
                 .debug_tuple("AsyncFn")
                 //.field(arg0)
                 .finish(),
+            Self::ReplaceFn(_) => f.debug_tuple("ReplaceFn").finish(),
+            Self::AsyncReplaceFn(_) => f.debug_tuple("AsyncReplaceFn").finish(),
+        }
+    }
+}
+
+/// Function signature for sync `pre_create` callbacks
+type SyncPreCreateFn<M> = dyn Fn(&M) -> HookResult<<M as Manager>::Error> + Sync + Send;
+
+/// Function signature for async `pre_create` callbacks
+type AsyncPreCreateFn<M> =
+    dyn for<'a> Fn(&'a M) -> HookFuture<'a, <M as Manager>::Error> + Sync + Send;
+
+/// Wrapper for `pre_create` hook functions
+///
+/// Unlike [`Hook`]s these are called before an object exists and receive the
+/// [`Manager`] instead.
+pub enum PreCreateHook<M: Manager> {
+    /// Use a plain function (non-async) as a hook
+    Fn(Box<SyncPreCreateFn<M>>),
+    /// Use an async function as a hook
+    AsyncFn(Box<AsyncPreCreateFn<M>>),
+}
+
+impl<M: Manager> PreCreateHook<M> {
+    /// Create PreCreateHook from sync function
+    pub fn sync_fn(f: impl Fn(&M) -> HookResult<M::Error> + Sync + Send + 'static) -> Self {
+        Self::Fn(Box::new(f))
+    }
+    /// Create PreCreateHook from async function
+    pub fn async_fn(
+        f: impl for<'a> Fn(&'a M) -> HookFuture<'a, M::Error> + Sync + Send + 'static,
+    ) -> Self {
+        Self::AsyncFn(Box::new(f))
+    }
+}
+
+impl<M: Manager> fmt::Debug for PreCreateHook<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fn(_) => f.debug_tuple("Fn").finish(),
+            Self::AsyncFn(_) => f.debug_tuple("AsyncFn").finish(),
         }
     }
 }
@@ -69,6 +166,21 @@ pub enum HookError<E> {
 
     /// Error caused by the backend.
     Backend(E),
+
+    /// Hook failed due to a transient error and the object should be
+    /// discarded and created again.
+    ///
+    /// This is only supported by `post_create` hooks. Instead of returning a
+    /// [`PoolError::PostCreateHook`] the [`Pool`] discards the object and
+    /// creates a new one. All attempts share the same [`Timeouts::create`].
+    /// After 16 retries the error is returned as
+    /// [`PoolError::PostCreateHook`]. For all other hooks this behaves like
+    /// any other error.
+    ///
+    /// [`Pool`]: super::Pool
+    /// [`PoolError::PostCreateHook`]: super::PoolError::PostCreateHook
+    /// [`Timeouts::create`]: super::Timeouts::create
+    Retry(Cow<'static, str>),
 }
 
 impl<E> HookError<E> {
@@ -77,6 +189,12 @@ impl<E> HookError<E> {
     pub fn message(msg: impl Into<Cow<'static, str>>) -> Self {
         Self::Message(msg.into())
     }
+
+    /// Convenience constructor function for the `HookError::Retry`
+    /// variant.
+    pub fn retry(msg: impl Into<Cow<'static, str>>) -> Self {
+        Self::Retry(msg.into())
+    }
 }
 
 impl<E: fmt::Display> fmt::Display for HookError<E> {
@@ -84,6 +202,7 @@ impl<E: fmt::Display> fmt::Display for HookError<E> {
         match self {
             Self::Message(msg) => write!(f, "{}", msg),
             Self::Backend(e) => write!(f, "{}", e),
+            Self::Retry(msg) => write!(f, "{}", msg),
         }
     }
 }
@@ -91,7 +210,7 @@ impl<E: fmt::Display> fmt::Display for HookError<E> {
 impl<E: std::error::Error + 'static> std::error::Error for HookError<E> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::Message(_) => None,
+            Self::Message(_) | Self::Retry(_) => None,
             Self::Backend(e) => Some(e),
         }
     }
@@ -120,13 +239,20 @@ impl<M: Manager> Default for HookVec<M> {
 impl<M: Manager> HookVec<M> {
     pub(crate) async fn apply(
         &self,
+        manager: &M,
         inner: &mut ObjectInner<M>,
     ) -> Result<(), HookError<M::Error>> {
         for hook in &self.vec {
-            match hook {
-                Hook::Fn(f) => f(&mut inner.obj, &inner.metrics)?,
-                Hook::AsyncFn(f) => f(&mut inner.obj, &inner.metrics).await?,
+            let replacement = match hook {
+                Hook::Fn(f) => f(&mut inner.obj, &inner.metrics).map(|()| None)?,
+                Hook::AsyncFn(f) => f(&mut inner.obj, &inner.metrics).await.map(|()| None)?,
+                Hook::ReplaceFn(f) => f(&mut inner.obj, &inner.metrics)?,
+                Hook::AsyncReplaceFn(f) => f(&mut inner.obj, &inner.metrics).await?,
             };
+            if let Some(obj) = replacement {
+                let mut replaced = std::mem::replace(&mut inner.obj, obj);
+                manager.detach(&mut replaced);
+            }
         }
         Ok(())
     }
@@ -135,10 +261,44 @@ impl<M: Manager> HookVec<M> {
     }
 }
 
+pub(crate) struct PreCreateHookVec<M: Manager> {
+    vec: Vec<PreCreateHook<M>>,
+}
+
+// Implemented manually to avoid unnecessary trait bound on `M` type parameter.
+impl<M: Manager> fmt::Debug for PreCreateHookVec<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PreCreateHookVec").finish_non_exhaustive()
+    }
+}
+
+// Implemented manually to avoid unnecessary trait bound on `M` type parameter.
+impl<M: Manager> Default for PreCreateHookVec<M> {
+    fn default() -> Self {
+        Self { vec: Vec::new() }
+    }
+}
+
+impl<M: Manager> PreCreateHookVec<M> {
+    pub(crate) async fn apply(&self, manager: &M) -> Result<(), HookError<M::Error>> {
+        for hook in &self.vec {
+            match hook {
+                PreCreateHook::Fn(f) => f(manager)?,
+                PreCreateHook::AsyncFn(f) => f(manager).await?,
+            };
+        }
+        Ok(())
+    }
+    pub(crate) fn push(&mut self, hook: PreCreateHook<M>) {
+        self.vec.push(hook);
+    }
+}
+
 /// Collection of all the hooks that can be configured for a [`Pool`].
 ///
 /// [`Pool`]: super::Pool
 pub(crate) struct Hooks<M: Manager> {
+    pub(crate) pre_create: PreCreateHookVec<M>,
     pub(crate) post_create: HookVec<M>,
     pub(crate) pre_recycle: HookVec<M>,
     pub(crate) post_recycle: HookVec<M>,
@@ -148,6 +308,7 @@ pub(crate) struct Hooks<M: Manager> {
 impl<M: Manager> fmt::Debug for Hooks<M> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Hooks")
+            .field("pre_create", &self.pre_create)
             .field("post_create", &self.post_create)
             .field("pre_recycle", &self.post_recycle)
             .field("post_recycle", &self.post_recycle)
@@ -159,6 +320,7 @@ impl<M: Manager> fmt::Debug for Hooks<M> {
 impl<M: Manager> Default for Hooks<M> {
     fn default() -> Self {
         Self {
+            pre_create: PreCreateHookVec::default(),
             pre_recycle: HookVec::default(),
             post_create: HookVec::default(),
             post_recycle: HookVec::default(),
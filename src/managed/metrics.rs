@@ -1,5 +1,8 @@
+use std::time::Duration;
 #[cfg(not(target_arch = "wasm32"))]
-use std::time::{Duration, Instant};
+use std::time::Instant;
+
+use crate::Status;
 
 /// Statistics regarding an object returned by the pool
 #[derive(Clone, Copy, Debug)]
@@ -13,11 +16,19 @@ pub struct Metrics {
     pub recycled: Option<Instant>,
     /// The number of times the objects was recycled
     pub recycle_count: usize,
+    /// The number of times the object was handed out by the pool
+    pub usage_count: usize,
 }
 
 impl Metrics {
     #[cfg(not(target_arch = "wasm32"))]
     /// Access the age of this object
+    ///
+    /// This is the time elapsed since the object was created. Combined with
+    /// [`Pool::retain()`] it can be used to implement a custom maximum
+    /// lifetime for objects.
+    ///
+    /// [`Pool::retain()`]: super::Pool::retain
     pub fn age(&self) -> Duration {
         self.created.elapsed()
     }
@@ -36,6 +47,175 @@ impl Default for Metrics {
             #[cfg(not(target_arch = "wasm32"))]
             recycled: None,
             recycle_count: 0,
+            usage_count: 0,
+        }
+    }
+}
+
+/// Statistics regarding the time spent waiting for a slot to become available
+/// when calling [`Pool::get()`].
+///
+/// Like [`Status`] these numbers are not guaranteed to be consistent and
+/// are meant for an overall insight.
+///
+/// [`Pool::get()`]: super::Pool::get
+/// [`Status`]: crate::Status
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WaitStats {
+    /// The number of times a slot has been acquired.
+    pub count: u64,
+    /// The total time spent waiting for slots.
+    pub total: Duration,
+    /// The time spent waiting for the most recently acquired slot.
+    pub last: Duration,
+}
+
+impl WaitStats {
+    /// Get the average time spent waiting for a slot
+    #[must_use]
+    pub fn average(&self) -> Duration {
+        match self.count {
+            0 => Duration::ZERO,
+            count => Duration::from_nanos((self.total.as_nanos() / u128::from(count)) as u64),
+        }
+    }
+}
+
+/// Number of timeouts which occurred per [`TimeoutType`] since the [`Pool`]
+/// was created.
+///
+/// [`Pool`]: super::Pool
+/// [`TimeoutType`]: super::TimeoutType
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TimeoutCounts {
+    /// The number of [`TimeoutType::Wait`] timeouts.
+    ///
+    /// [`TimeoutType::Wait`]: super::TimeoutType::Wait
+    pub wait: u64,
+    /// The number of [`TimeoutType::Create`] timeouts.
+    ///
+    /// [`TimeoutType::Create`]: super::TimeoutType::Create
+    pub create: u64,
+    /// The number of [`TimeoutType::Recycle`] timeouts.
+    ///
+    /// [`TimeoutType::Recycle`]: super::TimeoutType::Recycle
+    pub recycle: u64,
+}
+
+/// Number of objects created and detached since the [`Pool`] was created.
+///
+/// This is returned by [`Pool::object_counts()`].
+///
+/// [`Pool`]: super::Pool
+/// [`Pool::object_counts()`]: super::Pool::object_counts
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ObjectCounts {
+    /// The number of objects created by the [`Manager`].
+    ///
+    /// [`Manager`]: super::Manager
+    pub created: u64,
+    /// The number of objects detached from the [`Pool`], e.g. because they
+    /// failed to recycle, were invalidated or taken via [`Object::take()`].
+    ///
+    /// [`Pool`]: super::Pool
+    /// [`Object::take()`]: super::Object::take
+    pub detached: u64,
+}
+
+/// Detailed status of a [`Pool`] including statistics about the idle objects.
+///
+/// This is returned by [`Pool::status_detailed()`]. All statistics about the
+/// idle objects are zero if there are no idle objects.
+///
+/// [`Pool`]: super::Pool
+/// [`Pool::status_detailed()`]: super::Pool::status_detailed
+#[derive(Clone, Copy, Debug)]
+pub struct DetailedStatus {
+    /// The same counts as returned by [`Pool::status()`].
+    ///
+    /// [`Pool::status()`]: super::Pool::status
+    pub status: Status,
+    /// The number of idle objects.
+    pub idle: usize,
+    #[cfg(not(target_arch = "wasm32"))]
+    /// The age of the youngest idle object.
+    pub min_age: Duration,
+    #[cfg(not(target_arch = "wasm32"))]
+    /// The age of the oldest idle object.
+    pub max_age: Duration,
+    #[cfg(not(target_arch = "wasm32"))]
+    /// The average age of the idle objects.
+    pub avg_age: Duration,
+    /// The lowest recycle count of the idle objects.
+    pub min_recycle_count: usize,
+    /// The highest recycle count of the idle objects.
+    pub max_recycle_count: usize,
+    /// The average recycle count of the idle objects.
+    pub avg_recycle_count: f64,
+    /// The lowest usage count of the idle objects.
+    pub min_usage_count: usize,
+    /// The highest usage count of the idle objects.
+    pub max_usage_count: usize,
+    /// The average usage count of the idle objects.
+    pub avg_usage_count: f64,
+}
+
+impl DetailedStatus {
+    pub(crate) fn new<'a>(
+        status: Status,
+        metrics: impl ExactSizeIterator<Item = &'a Metrics>,
+    ) -> Self {
+        let idle = metrics.len();
+        let mut detailed = Self {
+            status,
+            idle,
+            #[cfg(not(target_arch = "wasm32"))]
+            min_age: Duration::MAX,
+            #[cfg(not(target_arch = "wasm32"))]
+            max_age: Duration::ZERO,
+            #[cfg(not(target_arch = "wasm32"))]
+            avg_age: Duration::ZERO,
+            min_recycle_count: usize::MAX,
+            max_recycle_count: 0,
+            avg_recycle_count: 0.0,
+            min_usage_count: usize::MAX,
+            max_usage_count: 0,
+            avg_usage_count: 0.0,
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        let mut total_age = Duration::ZERO;
+        let mut total_recycle_count = 0;
+        let mut total_usage_count = 0;
+        for metrics in metrics {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                let age = metrics.age();
+                detailed.min_age = detailed.min_age.min(age);
+                detailed.max_age = detailed.max_age.max(age);
+                total_age += age;
+            }
+            detailed.min_recycle_count = detailed.min_recycle_count.min(metrics.recycle_count);
+            detailed.max_recycle_count = detailed.max_recycle_count.max(metrics.recycle_count);
+            total_recycle_count += metrics.recycle_count;
+            detailed.min_usage_count = detailed.min_usage_count.min(metrics.usage_count);
+            detailed.max_usage_count = detailed.max_usage_count.max(metrics.usage_count);
+            total_usage_count += metrics.usage_count;
+        }
+        if idle == 0 {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                detailed.min_age = Duration::ZERO;
+            }
+            detailed.min_recycle_count = 0;
+            detailed.min_usage_count = 0;
+        } else {
+            #[cfg(not(target_arch = "wasm32"))]
+            {
+                detailed.avg_age = total_age / idle as u32;
+            }
+            detailed.avg_recycle_count = total_recycle_count as f64 / idle as f64;
+            detailed.avg_usage_count = total_usage_count as f64 / idle as f64;
         }
+        detailed
     }
 }
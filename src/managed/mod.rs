@@ -50,42 +50,87 @@
 
 mod builder;
 mod config;
-mod dropguard;
 mod errors;
+#[cfg(feature = "metrics")]
+mod exporter;
+mod extensions;
 mod hooks;
 mod metrics;
+mod observer;
 pub mod reexports;
+mod semaphore;
+#[cfg(feature = "sharded")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sharded")))]
+pub mod sharded;
 
 use std::{
+    any::Any,
     collections::VecDeque,
     fmt,
-    future::Future,
+    future::{poll_fn, Future},
     marker::PhantomData,
+    mem,
     ops::{Deref, DerefMut},
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
     sync::{
-        atomic::{AtomicUsize, Ordering},
+        atomic::{AtomicU64, AtomicUsize, Ordering},
         Arc, Mutex, Weak,
     },
-    time::Duration,
+    task::Poll,
+    time::{Duration, Instant},
 };
 
-#[cfg(not(target_arch = "wasm32"))]
-use std::time::Instant;
-
-use deadpool_runtime::Runtime;
-use tokio::sync::{Semaphore, TryAcquireError};
+use deadpool_runtime::{Runtime, SpawnHandle};
+use tokio::sync::{Notify, OwnedSemaphorePermit, TryAcquireError};
 
 pub use crate::Status;
 
-use self::dropguard::DropGuard;
+use crate::dropguard::DropGuard;
+
+use self::errors::CreateError;
+#[cfg(feature = "metrics")]
+use self::exporter::MetricsExporter;
+use self::semaphore::Semaphore;
 pub use self::{
     builder::{BuildError, PoolBuilder},
-    config::{CreatePoolError, PoolConfig, QueueMode, Timeouts},
+    config::{
+        CreatePoolError, GetOptions, MaintenanceConfig, PoolConfig, QueueMode, RetryConfig,
+        Timeouts, WaiterMode,
+    },
     errors::{PoolError, RecycleError, TimeoutType},
-    hooks::{Hook, HookError, HookFuture, HookResult},
-    metrics::Metrics,
+    extensions::Extensions,
+    hooks::{
+        Hook, HookError, HookFuture, HookResult, PreCreateHook, ReplaceHookFuture,
+        ReplaceHookResult,
+    },
+    metrics::{DetailedStatus, Metrics, ObjectCounts, TimeoutCounts, WaitStats},
+    observer::PoolObserver,
 };
 
+/// Maximum number of times a new object is created again after a
+/// `post_create` hook returned [`HookError::Retry`].
+const MAX_POST_CREATE_RETRIES: usize = 16;
+
+/// Emits a `tracing` debug event including the current [`Status`] of the
+/// given [`PoolInner`]. This does nothing unless the `tracing` feature is
+/// enabled. It must not be used while holding the lock of the slots.
+macro_rules! trace_event {
+    ($inner:expr, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        {
+            tracing::debug!(
+                target: "deadpool",
+                status = ?{
+                    let _slots = $inner.slots.lock().unwrap();
+                    $inner.status()
+                },
+                $($arg)+
+            );
+        }
+    };
+}
+
 /// Result type of the [`Manager::recycle()`] method.
 pub type RecycleResult<E> = Result<(), RecycleError<E>>;
 
@@ -125,6 +170,10 @@ pub trait Manager: Sync + Send {
 ///
 /// Use this object just as if it was of type `T` and upon leaving a scope the
 /// [`Drop::drop()`] will take care of returning it to the pool.
+///
+/// An [`Object`] only holds a weak reference to its [`Pool`] and is therefore
+/// `Send + 'static` as long as [`Manager::Type`] is [`Send`], even if it is
+/// not [`Sync`]. This makes it possible to move it into spawned tasks.
 #[must_use]
 pub struct Object<M: Manager> {
     /// The actual object
@@ -132,6 +181,13 @@ pub struct Object<M: Manager> {
 
     /// Pool to return the pooled object to.
     pool: Weak<PoolInner<M>>,
+
+    /// Whether this object was marked as invalid via [`Object::invalidate()`].
+    invalid: bool,
+
+    /// Permit of the [`PoolBuilder::shared_limit()`] semaphore which is
+    /// released after the object was returned to its [`Pool`].
+    shared_permit: Option<OwnedSemaphorePermit>,
 }
 
 impl<M> fmt::Debug for Object<M>
@@ -142,6 +198,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Object")
             .field("inner", &self.inner)
+            .field("invalid", &self.invalid)
             .finish()
     }
 }
@@ -163,8 +220,8 @@ impl<M: Manager> UnreadyObject<'_, M> {
 impl<M: Manager> Drop for UnreadyObject<'_, M> {
     fn drop(&mut self) {
         if let Some(mut inner) = self.inner.take() {
-            self.pool.slots.lock().unwrap().size -= 1;
-            self.pool.manager.detach(&mut inner.obj);
+            self.pool.remove_size(1);
+            self.pool.detach(&mut inner.obj);
         }
     }
 }
@@ -176,6 +233,9 @@ pub(crate) struct ObjectInner<M: Manager> {
 
     /// Object metrics.
     metrics: Metrics,
+
+    /// User data attached to this object.
+    extensions: Extensions,
 }
 
 impl<M: Manager> Object<M> {
@@ -190,11 +250,128 @@ impl<M: Manager> Object<M> {
         inner
     }
 
+    /// Splits this [`Object`] into the underlying object and an
+    /// [`ObjectHandle`] which can be used to return it to its [`Pool`] later
+    /// via [`Object::from_parts()`].
+    ///
+    /// Unlike [`Object::take()`] the object keeps occupying its slot in the
+    /// [`Pool`] until the [`ObjectHandle`] is either joined with an object
+    /// again or dropped:
+    ///
+    /// - [`Object::from_parts()`] turns it back into a regular [`Object`]
+    ///   which is returned to the [`Pool`] when dropped.
+    /// - Dropping the [`ObjectHandle`] frees the slot and counts the object
+    ///   as detached (see [`Pool::object_counts()`]) just like
+    ///   [`Object::take()`] does. As the [`ObjectHandle`] doesn't own the
+    ///   object anymore neither [`Manager::detach()`] nor
+    ///   [`PoolObserver::on_detach()`] are called in this case. Use
+    ///   `Object::take(Object::from_parts(obj, handle))` for keeping the
+    ///   object if the [`Manager`] needs to know about it.
+    ///
+    /// This is similar to removing an object from an [`unmanaged::Pool`] and
+    /// adding it back afterwards, except that the [`Pool`] can't hand out
+    /// the slot to anyone else in the meantime.
+    ///
+    /// [`unmanaged::Pool`]: crate::unmanaged::Pool
+    pub fn into_parts(mut this: Self) -> (M::Type, ObjectHandle<M>) {
+        let ObjectInner {
+            obj,
+            metrics,
+            extensions,
+        } = this.inner.take().unwrap();
+        let handle = ObjectHandle {
+            inner: Some((metrics, extensions)),
+            pool: mem::take(&mut this.pool),
+            invalid: this.invalid,
+            shared_permit: this.shared_permit.take(),
+        };
+        (obj, handle)
+    }
+
+    /// Joins an object and the [`ObjectHandle`] returned by
+    /// [`Object::into_parts()`] into an [`Object`] again.
+    ///
+    /// The given `obj` doesn't need to be the one which was split off the
+    /// [`ObjectHandle`]. It takes over the slot as well as the [`Metrics`]
+    /// and [`Extensions`] of the original object and is returned to the
+    /// [`Pool`] once the resulting [`Object`] is dropped.
+    pub fn from_parts(obj: M::Type, mut handle: ObjectHandle<M>) -> Self {
+        let (metrics, extensions) = handle.inner.take().unwrap();
+        Self {
+            inner: Some(ObjectInner {
+                obj,
+                metrics,
+                extensions,
+            }),
+            pool: mem::take(&mut handle.pool),
+            invalid: handle.invalid,
+            shared_permit: handle.shared_permit.take(),
+        }
+    }
+
+    /// Marks this [`Object`] as invalid so it is detached instead of being
+    /// returned to its [`Pool`] when dropped.
+    ///
+    /// Unlike [`Object::take()`] this frees up the slot of this [`Object`]
+    /// so the [`Pool`] can create a fresh object in its place. This is
+    /// useful when the object is known to be broken, e.g. after a backend
+    /// error occurred.
+    pub fn invalidate(this: &mut Self) {
+        this.invalid = true;
+    }
+
+    /// Checks this [`Object`] right away by calling [`Manager::recycle()`]
+    /// without returning it to its [`Pool`] first.
+    ///
+    /// This is useful for validating a connection right before an expensive
+    /// operation. On success the [`Metrics`] of this [`Object`] are updated
+    /// just like when it is recycled by the [`Pool`]. On failure this
+    /// [`Object`] is marked as invalid (see [`Object::invalidate()`]) so it
+    /// is detached from its [`Pool`] once dropped.
+    ///
+    /// Unlike recycling done by the [`Pool`] no hooks are applied and no
+    /// [`Timeouts::recycle`] is enforced.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`RecycleError`] of the [`Manager`] or a
+    /// [`RecycleError::Message`] if the [`Pool`] has been dropped already.
+    pub async fn recycle_now(this: &mut Self) -> RecycleResult<M::Error> {
+        let Some(pool) = this.pool.upgrade() else {
+            this.invalid = true;
+            return Err(RecycleError::message("Pool has been dropped"));
+        };
+        let inner = this.inner.as_mut().unwrap();
+        if let Err(e) = pool.manager.recycle(&mut inner.obj, &inner.metrics).await {
+            this.invalid = true;
+            return Err(e);
+        }
+        inner.metrics.recycle_count += 1;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            inner.metrics.recycled = Some(Instant::now());
+        }
+        Ok(())
+    }
+
     /// Get object statistics
     pub fn metrics(this: &Self) -> &Metrics {
         &this.inner.as_ref().unwrap().metrics
     }
 
+    /// Returns the [`Extensions`] attached to this [`Object`].
+    pub fn extensions(this: &Self) -> &Extensions {
+        &this.inner.as_ref().unwrap().extensions
+    }
+
+    /// Returns the [`Extensions`] attached to this [`Object`] mutably.
+    ///
+    /// Values inserted here stay attached to the underlying object when it
+    /// is returned to the [`Pool`] and handed out again.
+    pub fn extensions_mut(this: &mut Self) -> &mut Extensions {
+        &mut this.inner.as_mut().unwrap().extensions
+    }
+
     /// Returns the [`Pool`] this [`Object`] belongs to.
     ///
     /// Since [`Object`]s only hold a [`Weak`] reference to the [`Pool`] they
@@ -209,11 +386,69 @@ impl<M: Manager> Object<M> {
 
 impl<M: Manager> Drop for Object<M> {
     fn drop(&mut self) {
-        if let Some(inner) = self.inner.take() {
+        if let Some(mut inner) = self.inner.take() {
+            if let Some(pool) = self.pool.upgrade() {
+                if self.invalid {
+                    pool.detach_object(&mut inner.obj)
+                } else {
+                    pool.return_object(inner)
+                }
+            }
+        }
+        // Release the shared permit only after the slot was given back so
+        // a task woken up by it can't observe this pool as exhausted.
+        drop(self.shared_permit.take());
+    }
+}
+
+/// Slot of an [`Object`] whose underlying object was split off using
+/// [`Object::into_parts()`].
+///
+/// The slot stays occupied until this handle is joined with an object again
+/// using [`Object::from_parts()`] or dropped. See [`Object::into_parts()`]
+/// for details.
+#[must_use]
+pub struct ObjectHandle<M: Manager> {
+    /// Metrics and extensions of the split off object.
+    inner: Option<(Metrics, Extensions)>,
+
+    /// Pool the slot belongs to.
+    pool: Weak<PoolInner<M>>,
+
+    /// Whether the object was marked as invalid via [`Object::invalidate()`].
+    invalid: bool,
+
+    /// Permit of the [`PoolBuilder::shared_limit()`] semaphore.
+    shared_permit: Option<OwnedSemaphorePermit>,
+}
+
+impl<M: Manager> ObjectHandle<M> {
+    /// Get the statistics of the split off object.
+    pub fn metrics(&self) -> &Metrics {
+        &self.inner.as_ref().unwrap().0
+    }
+}
+
+impl<M: Manager> fmt::Debug for ObjectHandle<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObjectHandle")
+            .field("metrics", &self.inner.as_ref().map(|(metrics, _)| metrics))
+            .field("invalid", &self.invalid)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M: Manager> Drop for ObjectHandle<M> {
+    fn drop(&mut self) {
+        if self.inner.take().is_some() {
             if let Some(pool) = self.pool.upgrade() {
-                pool.return_object(inner)
+                pool.remove_slot();
+                pool.count_detached();
+                pool.release_user();
+                pool.notify_min_idle();
             }
         }
+        drop(self.shared_permit.take());
     }
 }
 
@@ -289,14 +524,29 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
                 manager: builder.manager,
                 slots: Mutex::new(Slots {
                     vec: VecDeque::with_capacity(builder.config.max_size),
-                    size: 0,
-                    max_size: builder.config.max_size,
                 }),
+                size: AtomicUsize::new(0),
+                max_size: AtomicUsize::new(builder.config.max_size),
                 users: AtomicUsize::new(0),
-                semaphore: Semaphore::new(builder.config.max_size),
+                semaphore: Semaphore::new(builder.config.waiter_mode, builder.config.max_size),
                 config: builder.config,
                 hooks: builder.hooks,
+                observer: builder.observer,
+                shared_limit: builder.shared_limit,
                 runtime: builder.runtime,
+                wait_count: AtomicU64::new(0),
+                wait_total_nanos: AtomicU64::new(0),
+                wait_last_nanos: AtomicU64::new(0),
+                timeouts_wait: AtomicU64::new(0),
+                timeouts_create: AtomicU64::new(0),
+                timeouts_recycle: AtomicU64::new(0),
+                objects_created: AtomicU64::new(0),
+                objects_detached: AtomicU64::new(0),
+                min_idle_notify: Arc::new(Notify::new()),
+                min_idle_task: Mutex::new(None),
+                drained_notify: Notify::new(),
+                #[cfg(feature = "metrics")]
+                metrics_exporter: std::sync::OnceLock::new(),
             }),
             _wrapper: PhantomData,
         }
@@ -305,6 +555,15 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
     /// Retrieves an [`Object`] from this [`Pool`] or waits for one to
     /// become available.
     ///
+    /// The returned future is [`Send`] and so is the returned object as long
+    /// as `W` is [`Send`]. Both can therefore be used inside spawned tasks.
+    ///
+    /// With the default [`WaiterMode::Fifo`] waiting tasks are served in the
+    /// order they started waiting, regardless of which clone of this
+    /// [`Pool`] they use. Once a task has been granted a slot it keeps it
+    /// while objects are recycled or created, so failing recycles never
+    /// force it to queue up again.
+    ///
     /// # Errors
     ///
     /// See [`PoolError`] for details.
@@ -312,6 +571,44 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
         self.timeout_get(&self.timeouts()).await
     }
 
+    /// Retrieves an [`Object`] from this [`Pool`] just like [`Pool::get()`]
+    /// and returns it together with the time it took to acquire it.
+    ///
+    /// The returned [`Duration`] includes the time spent waiting for a slot
+    /// as well as creating or recycling the [`Object`]. Unlike
+    /// [`Pool::wait_stats()`] this only covers this single call, which makes
+    /// it useful for shedding load based on the latency observed by the
+    /// caller.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn get_timed(&self) -> Result<(W, Duration), PoolError<M::Error>> {
+        let start = Instant::now();
+        let obj = self.timeout_get(&self.timeouts()).await?;
+        Ok((obj, start.elapsed()))
+    }
+
+    /// Retrieves an [`Object`] from this [`Pool`] without waiting for a slot
+    /// to become available.
+    ///
+    /// Existing [`Object`]s are still recycled and new ones created if
+    /// needed, which is why this method is `async`. If the [`Pool`] is
+    /// exhausted [`PoolError::Timeout`] with [`TimeoutType::Wait`] is
+    /// returned immediately.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    pub async fn try_get(&self) -> Result<W, PoolError<M::Error>> {
+        self.timeout_get(&Timeouts {
+            wait: Some(Duration::ZERO),
+            ..self.timeouts()
+        })
+        .await
+    }
+
     /// Retrieves an [`Object`] from this [`Pool`] using a different `timeout`
     /// than the configured one.
     ///
@@ -319,44 +616,135 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
     ///
     /// See [`PoolError`] for details.
     pub async fn timeout_get(&self, timeouts: &Timeouts) -> Result<W, PoolError<M::Error>> {
+        self.deadline_timeout_get(timeouts, None, GetOptions::default())
+            .await
+    }
+
+    /// Retrieves an [`Object`] from this [`Pool`] using the given
+    /// [`GetOptions`].
+    ///
+    /// This makes it possible to bypass the recycling hooks and/or
+    /// [`Manager::recycle()`] for specific call sites, e.g. cheap liveness
+    /// probes, while keeping the common path unchanged.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    pub async fn get_with(&self, options: GetOptions) -> Result<W, PoolError<M::Error>> {
+        self.deadline_timeout_get(&self.timeouts(), None, options)
+            .await
+    }
+
+    /// Retrieves an [`Object`] from this [`Pool`] before the given `deadline`.
+    ///
+    /// The time remaining until the `deadline` limits the configured
+    /// [`Timeouts`] of each step. If the `deadline` has already passed
+    /// [`PoolError::Timeout`] with [`TimeoutType::Wait`] is returned right
+    /// away.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError::NoRuntimeSpecified`] right away if the [`Pool`]
+    /// was built without a [`Runtime`] as the `deadline` can't be enforced
+    /// without one. See [`PoolError`] for the other errors.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn get_deadline(&self, deadline: Instant) -> Result<W, PoolError<M::Error>> {
+        if self.inner.runtime.is_none() {
+            return Err(PoolError::NoRuntimeSpecified);
+        }
+        if deadline <= Instant::now() {
+            self.inner.on_timeout(TimeoutType::Wait);
+            return Err(PoolError::Timeout(TimeoutType::Wait));
+        }
+        self.deadline_timeout_get(&self.timeouts(), Some(deadline), GetOptions::default())
+            .await
+    }
+
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "get", target = "deadpool", level = "debug", skip_all)
+    )]
+    async fn deadline_timeout_get(
+        &self,
+        timeouts: &Timeouts,
+        deadline: Option<Instant>,
+        options: GetOptions,
+    ) -> Result<W, PoolError<M::Error>> {
         let _ = self.inner.users.fetch_add(1, Ordering::Relaxed);
-        let users_guard = DropGuard(|| {
-            let _ = self.inner.users.fetch_sub(1, Ordering::Relaxed);
-        });
+        let users_guard = DropGuard(|| self.inner.release_user());
+        self.inner.update_metrics();
 
-        let non_blocking = match timeouts.wait {
+        let wait_timeout = limit_timeout(timeouts.wait, deadline);
+        let non_blocking = match wait_timeout {
             Some(t) => t.as_nanos() == 0,
             None => false,
         };
 
-        let permit = if non_blocking {
-            self.inner.semaphore.try_acquire().map_err(|e| match e {
+        #[cfg(not(target_arch = "wasm32"))]
+        let wait_start = Instant::now();
+
+        // The slot of this pool is always acquired before the permit of the
+        // shared limit. Waiting for a slot while holding a shared permit
+        // could starve other pools sharing the same limit.
+        let (permit, shared_permit) = if non_blocking {
+            let map_err = |e| match e {
                 TryAcquireError::Closed => PoolError::Closed,
-                TryAcquireError::NoPermits => PoolError::Timeout(TimeoutType::Wait),
-            })?
+                TryAcquireError::NoPermits => {
+                    self.inner.on_timeout(TimeoutType::Wait);
+                    PoolError::Timeout(TimeoutType::Wait)
+                }
+            };
+            let permit = self.inner.semaphore.try_acquire().map_err(map_err)?;
+            let shared_permit = match &self.inner.shared_limit {
+                Some(shared) => Some(shared.clone().try_acquire_owned().map_err(map_err)?),
+                None => None,
+            };
+            (permit, shared_permit)
         } else {
-            apply_timeout(
-                self.inner.runtime,
-                TimeoutType::Wait,
-                timeouts.wait,
-                async {
-                    self.inner
+            self.inner
+                .apply_timeout(TimeoutType::Wait, wait_timeout, async {
+                    let permit = self
+                        .inner
                         .semaphore
                         .acquire()
                         .await
-                        .map_err(|_| PoolError::Closed)
-                },
-            )
-            .await?
+                        .map_err(|_| PoolError::Closed)?;
+                    let shared_permit = match &self.inner.shared_limit {
+                        Some(shared) => Some(
+                            shared
+                                .clone()
+                                .acquire_owned()
+                                .await
+                                .map_err(|_| PoolError::Closed)?,
+                        ),
+                        None => None,
+                    };
+                    Ok::<_, PoolError<M::Error>>((permit, shared_permit))
+                })
+                .await?
         };
 
-        let inner_obj = loop {
+        #[cfg(not(target_arch = "wasm32"))]
+        self.inner.record_wait(wait_start.elapsed());
+        trace_event!(self.inner, "Slot acquired");
+
+        // The permit is held for the whole loop. Discarding objects which
+        // fail to recycle must not put this task back into the queue.
+        let mut inner_obj = loop {
+            let timeouts = &limit_timeouts(timeouts, deadline);
             let inner_obj = match self.inner.config.queue_mode {
                 QueueMode::Fifo => self.inner.slots.lock().unwrap().vec.pop_front(),
                 QueueMode::Lifo => self.inner.slots.lock().unwrap().vec.pop_back(),
+                QueueMode::LruByMetrics => {
+                    pop_most_recently_used(&mut self.inner.slots.lock().unwrap().vec)
+                }
             };
             let inner_obj = if let Some(inner_obj) = inner_obj {
-                self.try_recycle(timeouts, inner_obj).await?
+                let inner_obj = self.try_recycle(timeouts, options, inner_obj).await?;
+                if inner_obj.is_none() {
+                    self.inner.notify_min_idle();
+                }
+                inner_obj
             } else {
                 self.try_create(timeouts).await?
             };
@@ -367,18 +755,32 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
 
         users_guard.disarm();
         permit.forget();
+        inner_obj.metrics.usage_count += 1;
+
+        if let Some(observer) = &self.inner.observer {
+            observer.on_acquire(&inner_obj.metrics);
+        }
+        trace_event!(self.inner, "Object acquired");
+        self.inner.update_metrics();
 
         Ok(Object {
             inner: Some(inner_obj),
             pool: Arc::downgrade(&self.inner),
+            invalid: false,
+            shared_permit,
         }
         .into())
     }
 
     #[inline]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "recycle", target = "deadpool", level = "debug", skip_all)
+    )]
     async fn try_recycle(
         &self,
         timeouts: &Timeouts,
+        options: GetOptions,
         inner_obj: ObjectInner<M>,
     ) -> Result<Option<ObjectInner<M>>, PoolError<M::Error>> {
         let mut unready_obj = UnreadyObject {
@@ -387,28 +789,73 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
         };
         let inner = unready_obj.inner();
 
+        // Discard objects which exceeded their maximum lifetime
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(max_lifetime) = self.inner.config.max_lifetime {
+            if inner.metrics.age() > max_lifetime {
+                trace_event!(self.inner, "Object discarded: Maximum lifetime exceeded");
+                return Ok(None);
+            }
+        }
+
+        // Discard objects which have been idle for too long
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(idle_timeout) = self.inner.config.idle_timeout {
+            if inner.metrics.last_used() > idle_timeout {
+                trace_event!(self.inner, "Object discarded: Idle timeout exceeded");
+                return Ok(None);
+            }
+        }
+
         // Apply pre_recycle hooks
-        if let Err(_e) = self.inner.hooks.pre_recycle.apply(inner).await {
-            // TODO log pre_recycle error
-            return Ok(None);
+        if !options.skip_hooks {
+            if let Err(_e) = self
+                .inner
+                .hooks
+                .pre_recycle
+                .apply(&self.inner.manager, inner)
+                .await
+            {
+                // TODO log pre_recycle error
+                trace_event!(self.inner, "Object discarded: pre_recycle hook failed");
+                return Ok(None);
+            }
         }
 
-        if apply_timeout(
-            self.inner.runtime,
-            TimeoutType::Recycle,
-            timeouts.recycle,
-            self.inner.manager.recycle(&mut inner.obj, &inner.metrics),
-        )
-        .await
-        .is_err()
+        if !options.skip_recycle
+            && self
+                .inner
+                .apply_timeout(
+                    TimeoutType::Recycle,
+                    timeouts.recycle,
+                    self.inner.manager.recycle(&mut inner.obj, &inner.metrics),
+                )
+                .await
+                .is_err()
         {
+            trace_event!(self.inner, "Object discarded: Recycling failed");
             return Ok(None);
         }
 
         // Apply post_recycle hooks
-        if let Err(_e) = self.inner.hooks.post_recycle.apply(inner).await {
-            // TODO log post_recycle error
-            return Ok(None);
+        if !options.skip_hooks {
+            if let Err(_e) = self
+                .inner
+                .hooks
+                .post_recycle
+                .apply(&self.inner.manager, inner)
+                .await
+            {
+                // TODO log post_recycle error
+                trace_event!(self.inner, "Object discarded: post_recycle hook failed");
+                return Ok(None);
+            }
+        }
+
+        // Objects which weren't passed to `Manager::recycle()` don't count
+        // as recycled.
+        if options.skip_recycle {
+            return Ok(Some(unready_obj.ready()));
         }
 
         inner.metrics.recycle_count += 1;
@@ -417,42 +864,133 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
             inner.metrics.recycled = Some(Instant::now());
         }
 
+        if let Some(observer) = &self.inner.observer {
+            observer.on_recycle(&inner.obj, &inner.metrics);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(exporter) = self.inner.metrics_exporter.get() {
+            exporter.recycled.increment(1);
+        }
+        trace_event!(self.inner, "Object recycled");
+
         Ok(Some(unready_obj.ready()))
     }
 
     #[inline]
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "create", target = "deadpool", level = "debug", skip_all)
+    )]
     async fn try_create(
         &self,
         timeouts: &Timeouts,
     ) -> Result<Option<ObjectInner<M>>, PoolError<M::Error>> {
-        let mut unready_obj = UnreadyObject {
-            inner: Some(ObjectInner {
-                obj: apply_timeout(
-                    self.inner.runtime,
-                    TimeoutType::Create,
-                    timeouts.create,
-                    self.inner.manager.create(),
-                )
-                .await?,
-                metrics: Metrics::default(),
-            }),
-            pool: &self.inner,
-        };
+        // `post_create` hooks returning `HookError::Retry` cause the object
+        // to be discarded and a new one to be created. All attempts share
+        // the same create timeout. `Instant::now()` is not available on
+        // wasm32 where every attempt uses the full create timeout instead.
+        #[cfg(not(target_arch = "wasm32"))]
+        let deadline = timeouts.create.map(|timeout| Instant::now() + timeout);
+        #[cfg(target_arch = "wasm32")]
+        let deadline = None;
+        let mut retries = 0;
+        loop {
+            // Apply pre_create hooks
+            self.inner
+                .hooks
+                .pre_create
+                .apply(&self.inner.manager)
+                .await
+                .map_err(PoolError::PreCreateHook)?;
 
-        self.inner.slots.lock().unwrap().size += 1;
+            let mut unready_obj = UnreadyObject {
+                inner: Some(ObjectInner {
+                    obj: self
+                        .inner
+                        .apply_timeout(
+                            TimeoutType::Create,
+                            limit_timeout(timeouts.create, deadline),
+                            self.inner.create(),
+                        )
+                        .await?,
+                    metrics: Metrics::default(),
+                    extensions: Extensions::default(),
+                }),
+                pool: &self.inner,
+            };
 
-        // Apply post_create hooks
-        if let Err(e) = self
-            .inner
-            .hooks
-            .post_create
-            .apply(unready_obj.inner())
-            .await
-        {
-            return Err(PoolError::PostCreateHook(e));
+            {
+                let _slots = self.inner.slots.lock().unwrap();
+                let _ = self.inner.size.fetch_add(1, Ordering::Relaxed);
+            }
+
+            // Apply post_create hooks
+            match self
+                .inner
+                .hooks
+                .post_create
+                .apply(&self.inner.manager, unready_obj.inner())
+                .await
+            {
+                Ok(()) => {}
+                Err(e @ HookError::Retry(_)) => {
+                    trace_event!(
+                        self.inner,
+                        "Object discarded: post_create hook requested retry"
+                    );
+                    if retries == MAX_POST_CREATE_RETRIES {
+                        return Err(PoolError::PostCreateHook(e));
+                    }
+                    #[cfg(not(target_arch = "wasm32"))]
+                    if deadline.is_some_and(|deadline| deadline <= Instant::now()) {
+                        self.inner.on_timeout(TimeoutType::Create);
+                        return Err(PoolError::Timeout(TimeoutType::Create));
+                    }
+                    retries += 1;
+                    continue;
+                }
+                Err(e) => return Err(PoolError::PostCreateHook(e)),
+            }
+
+            if let Some(observer) = &self.inner.observer {
+                observer.on_create(&unready_obj.inner().obj);
+            }
+            let _ = self.inner.objects_created.fetch_add(1, Ordering::Relaxed);
+            #[cfg(feature = "metrics")]
+            if let Some(exporter) = self.inner.metrics_exporter.get() {
+                exporter.created.increment(1);
+            }
+            trace_event!(self.inner, "Object created");
+
+            return Ok(Some(unready_obj.ready()));
         }
+    }
 
-        Ok(Some(unready_obj.ready()))
+    /// Creates new [`Object`]s until the [`Pool`] contains at least
+    /// [`PoolConfig::min_idle`] objects or no more slots are available.
+    async fn fill_min_idle(&self) -> Result<(), PoolError<M::Error>> {
+        match self.inner.config.min_idle {
+            Some(min_idle) => self.fill(min_idle).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Creates new [`Object`]s until the [`Pool`] contains at least `size`
+    /// objects or no more slots are available.
+    async fn fill(&self, size: usize) -> Result<(), PoolError<M::Error>> {
+        let timeouts = self.timeouts();
+        loop {
+            if self.current_size() >= size.min(self.max_size()) {
+                return Ok(());
+            }
+            // Hold a permit while creating so the pool can't overflow.
+            let Ok(_permit) = self.inner.semaphore.try_acquire() else {
+                return Ok(());
+            };
+            if let Some(inner_obj) = self.try_create(&timeouts).await? {
+                self.inner.slots.lock().unwrap().vec.push_back(inner_obj);
+            }
+        }
     }
 
     /**
@@ -463,19 +1001,51 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
      * always reports a `max_size` of 0 for closed pools.
      */
     pub fn resize(&self, max_size: usize) {
+        self.resize_with(max_size, drop);
+    }
+
+    /// Resizes the pool just like [`Pool::resize()`] and creates new
+    /// [`Object`]s until the [`Pool`] contains `max_size` objects.
+    ///
+    /// The objects are created one after another by the calling task, so no
+    /// [`Runtime`] is required. The returned future completes once all of
+    /// them have been added to the [`Pool`]. Borrowed objects count towards
+    /// the size of the [`Pool`]. Dropping the future stops creating objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError::Closed`] if the [`Pool`] has been closed.
+    /// Otherwise the first error which occurs while creating an object is
+    /// returned. The objects created before are kept.
+    pub async fn resize_and_fill(&self, max_size: usize) -> Result<(), PoolError<M::Error>> {
+        if self.is_closed() {
+            return Err(PoolError::Closed);
+        }
+        self.resize(max_size);
+        self.fill(max_size).await
+    }
+
+    /// Resizes the pool just like [`Pool::resize()`] but passes every object
+    /// removed while shrinking the pool to the given `evicted` function.
+    ///
+    /// The function is called after the internal lock has been released, so
+    /// it is safe to e.g. spawn a task performing an asynchronous shutdown
+    /// of the object.
+    pub fn resize_with(&self, max_size: usize, evicted: impl FnMut(M::Type)) {
         if self.inner.semaphore.is_closed() {
             return;
         }
+        let mut removed = Vec::new();
         let mut slots = self.inner.slots.lock().unwrap();
-        let old_max_size = slots.max_size;
-        slots.max_size = max_size;
+        let old_max_size = self.inner.max_size.swap(max_size, Ordering::Relaxed);
         // shrink pool
         if max_size < old_max_size {
-            while slots.size > slots.max_size {
+            while self.current_size() > max_size {
                 if let Ok(permit) = self.inner.semaphore.try_acquire() {
                     permit.forget();
-                    if slots.vec.pop_front().is_some() {
-                        slots.size -= 1;
+                    if let Some(obj) = slots.vec.pop_front() {
+                        let _ = self.inner.size.fetch_sub(1, Ordering::Relaxed);
+                        removed.push(obj);
                     }
                 } else {
                     break;
@@ -490,10 +1060,57 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
         }
         // grow pool
         if max_size > old_max_size {
-            let additional = slots.max_size - old_max_size;
+            let additional = max_size - old_max_size;
             slots.vec.reserve_exact(additional);
             self.inner.semaphore.add_permits(additional);
         }
+        drop(slots);
+        removed
+            .into_iter()
+            .map(|mut obj| {
+                self.inner.detach(&mut obj.obj);
+                obj.obj
+            })
+            .for_each(evicted);
+        self.inner.update_metrics();
+    }
+
+    /// Removes all idle objects from this [`Pool`] without closing it.
+    ///
+    /// Objects which are currently borrowed are not affected. The `max_size`
+    /// stays the same so subsequent calls to [`Pool::get()`] create new
+    /// objects as needed. This is useful when all idle objects are known to
+    /// be stale, e.g. after a database failover.
+    pub fn clear(&self) {
+        let mut slots = self.inner.slots.lock().unwrap();
+        let removed = slots.vec.drain(..).collect::<Vec<_>>();
+        let _ = self.inner.size.fetch_sub(removed.len(), Ordering::Relaxed);
+        drop(slots);
+        if removed.is_empty() {
+            return;
+        }
+        for mut obj in removed {
+            self.inner.detach(&mut obj.obj);
+        }
+        self.inner.notify_min_idle();
+        self.inner.update_metrics();
+    }
+
+    /// Calls the given function for every idle object of this [`Pool`].
+    ///
+    /// Unlike [`Pool::retain()`] this never removes any objects and only
+    /// passes a shared reference to the function. This is useful for
+    /// inspecting the [`Metrics`] of the idle objects e.g. for an admin
+    /// endpoint.
+    ///
+    /// **Caution:** This function blocks the entire pool while
+    /// it is running. Therefore the given function should not
+    /// block.
+    pub fn inspect(&self, mut f: impl FnMut(&M::Type, &Metrics)) {
+        let slots = self.inner.slots.lock().unwrap();
+        for obj in &slots.vec {
+            f(&obj.obj, &obj.metrics);
+        }
     }
 
     /// Retains only the objects specified by the given function.
@@ -519,6 +1136,13 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
     ///     }
     /// });
     /// ```
+    ///
+    /// Objects which are older than ten minutes can be removed by
+    /// checking their [`Metrics::age()`]:
+    ///
+    /// ```rust,ignore
+    /// pool.retain(|_, metrics| metrics.age() < Duration::from_secs(600));
+    /// ```
     pub fn retain(
         &self,
         mut predicate: impl FnMut(&M::Type, Metrics) -> bool,
@@ -534,17 +1158,77 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
                 i += 1;
             } else {
                 let mut obj = guard.vec.remove(i).unwrap();
-                self.manager().detach(&mut obj.obj);
+                self.inner.detach(&mut obj.obj);
                 removed.push(obj.obj);
             }
         }
-        guard.size -= removed.len();
+        let _ = self.inner.size.fetch_sub(removed.len(), Ordering::Relaxed);
+        drop(guard);
+        if !removed.is_empty() {
+            self.inner.notify_min_idle();
+            self.inner.update_metrics();
+        }
         RetainResult {
             retained: i,
             removed,
         }
     }
 
+    /// Retains only the objects specified by the given async function.
+    ///
+    /// Unlike [`Pool::retain()`] this doesn't block the entire pool while
+    /// running. Idle objects are checked out one at a time, passed to the
+    /// `predicate` and either returned to the pool or removed from it. This
+    /// makes it possible to validate objects using an actual round-trip to
+    /// the backend.
+    ///
+    /// Objects which are currently in use are not checked. If the pool is
+    /// exhausted while running this method it stops early.
+    ///
+    /// ```rust,ignore
+    /// let result = pool
+    ///     .retain_async(|conn, _| Box::pin(async move { conn.ping().await.is_ok() }))
+    ///     .await;
+    /// ```
+    pub async fn retain_async(
+        &self,
+        mut predicate: impl for<'a> FnMut(
+            &'a mut M::Type,
+            &'a Metrics,
+        ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>>,
+    ) -> RetainResult<M::Type> {
+        let mut result = RetainResult::default();
+        let count = self.inner.slots.lock().unwrap().vec.len();
+        for _ in 0..count {
+            let Ok(_permit) = self.inner.semaphore.try_acquire() else {
+                break;
+            };
+            let Some(inner_obj) = self.inner.slots.lock().unwrap().vec.pop_front() else {
+                break;
+            };
+            let mut unready_obj = UnreadyObject {
+                inner: Some(inner_obj),
+                pool: &self.inner,
+            };
+            let inner = unready_obj.inner();
+            let retain = predicate(&mut inner.obj, &inner.metrics).await;
+            let mut inner_obj = unready_obj.ready();
+            if retain {
+                self.inner.slots.lock().unwrap().vec.push_back(inner_obj);
+                result.retained += 1;
+            } else {
+                self.inner.remove_size(1);
+                self.inner.detach(&mut inner_obj.obj);
+                result.removed.push(inner_obj.obj);
+            }
+        }
+        if !result.removed.is_empty() {
+            self.inner.notify_min_idle();
+            self.inner.update_metrics();
+        }
+        result
+    }
+
     /// Get current timeout configuration
     pub fn timeouts(&self) -> Timeouts {
         self.inner.config.timeouts
@@ -561,26 +1245,153 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
         self.inner.semaphore.close();
     }
 
+    /// Closes this [`Pool`] just like [`Pool::close()`] and waits until all
+    /// borrowed [`Object`]s have been returned or taken.
+    ///
+    /// Returned [`Object`]s are detached and dropped, so once this method
+    /// returns the [`Pool`] doesn't hold any objects anymore. This is meant
+    /// to be used for draining all connections during a graceful shutdown.
+    pub async fn close_and_wait(&self) {
+        self.close();
+        loop {
+            // The `Notified` future must be created before checking the
+            // number of users so a notification in between isn't lost.
+            let drained = self.inner.drained_notify.notified();
+            if self.inner.users.load(Ordering::Relaxed) == 0 {
+                return;
+            }
+            drained.await;
+        }
+    }
+
     /// Indicates whether this [`Pool`] has been closed.
     pub fn is_closed(&self) -> bool {
         self.inner.semaphore.is_closed()
     }
 
+    /// Returns the number of [`Object`]s currently managed by this [`Pool`],
+    /// i.e. the available ones plus the ones in use.
+    ///
+    /// This is the same as [`Status::size`] but is read without locking or
+    /// building a [`Status`]. The value may already be outdated when used
+    /// and is not synchronized with other values, so use [`Pool::status()`]
+    /// for a consistent snapshot.
+    #[must_use]
+    pub fn current_size(&self) -> usize {
+        self.inner.size.load(Ordering::Relaxed)
+    }
+
+    /// Returns the maximum number of [`Object`]s this [`Pool`] can manage.
+    ///
+    /// This is the same as [`Status::max_size`] and is subject to the same
+    /// caveats as [`Pool::current_size()`].
+    #[must_use]
+    pub fn max_size(&self) -> usize {
+        self.inner.max_size.load(Ordering::Relaxed)
+    }
+
+    /// Indicates whether this [`Pool`] currently doesn't manage any
+    /// [`Object`]s.
+    ///
+    /// This is subject to the same caveats as [`Pool::current_size()`].
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.current_size() == 0
+    }
+
     /// Retrieves [`Status`] of this [`Pool`].
     #[must_use]
     pub fn status(&self) -> Status {
+        let _slots = self.inner.slots.lock().unwrap();
+        self.inner.status()
+    }
+
+    /// Retrieves [`DetailedStatus`] of this [`Pool`].
+    ///
+    /// In addition to the [`Status`] this contains statistics about the
+    /// [`Metrics`] of the idle objects. Both are retrieved while holding the
+    /// lock of the idle objects once so they are consistent with each other.
+    #[must_use]
+    pub fn status_detailed(&self) -> DetailedStatus {
         let slots = self.inner.slots.lock().unwrap();
-        let users = self.inner.users.load(Ordering::Relaxed);
-        let (available, waiting) = if users < slots.size {
-            (slots.size - users, 0)
-        } else {
-            (0, users - slots.size)
-        };
-        Status {
-            max_size: slots.max_size,
-            size: slots.size,
-            available,
-            waiting,
+        DetailedStatus::new(
+            self.inner.status(),
+            slots.vec.iter().map(|obj| &obj.metrics),
+        )
+    }
+
+    /// Retrieves [`WaitStats`] of this [`Pool`].
+    ///
+    /// This can be used to detect contention, e.g. when the `max_size` of
+    /// the [`Pool`] is too small for the given workload.
+    #[must_use]
+    pub fn wait_stats(&self) -> WaitStats {
+        WaitStats {
+            count: self.inner.wait_count.load(Ordering::Relaxed),
+            total: Duration::from_nanos(self.inner.wait_total_nanos.load(Ordering::Relaxed)),
+            last: Duration::from_nanos(self.inner.wait_last_nanos.load(Ordering::Relaxed)),
+        }
+    }
+
+    /// Retrieves the [`TimeoutCounts`] of this [`Pool`].
+    ///
+    /// This can be used to tell a [`Pool`] which is too small (`wait`
+    /// timeouts) from a slow backend (`create` and `recycle` timeouts).
+    #[must_use]
+    pub fn timeout_counts(&self) -> TimeoutCounts {
+        TimeoutCounts {
+            wait: self.inner.timeouts_wait.load(Ordering::Relaxed),
+            create: self.inner.timeouts_create.load(Ordering::Relaxed),
+            recycle: self.inner.timeouts_recycle.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Retrieves the [`ObjectCounts`] of this [`Pool`].
+    ///
+    /// Unlike [`Pool::status()`] these numbers never decrease which makes it
+    /// possible to detect objects being leaked or created over and over
+    /// again, e.g. by asserting an upper bound in tests.
+    #[must_use]
+    pub fn object_counts(&self) -> ObjectCounts {
+        ObjectCounts {
+            created: self.inner.objects_created.load(Ordering::Relaxed),
+            detached: self.inner.objects_detached.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Registers gauges and counters describing this [`Pool`] with the
+    /// [`metrics`](::metrics) crate.
+    ///
+    /// The metrics are registered with the currently installed
+    /// [`metrics::Recorder`](::metrics::Recorder) (e.g. the one of
+    /// `metrics-exporter-prometheus`) so this method must be called after
+    /// installing it. They are updated by the [`Pool`] itself whenever its
+    /// state changes so there is no need for polling [`Pool::status()`].
+    /// Only the first call has an effect, subsequent calls are ignored.
+    ///
+    /// The following metrics are provided, all of them prefixed with the
+    /// given `namespace` (e.g. `myapp_db_pool_size` for `myapp_db`):
+    ///
+    /// | Name | Type | Description |
+    /// | ---- | ---- | ----------- |
+    /// | `{namespace}_pool_max_size` | gauge | See [`Status::max_size`] |
+    /// | `{namespace}_pool_size` | gauge | See [`Status::size`] |
+    /// | `{namespace}_pool_available` | gauge | See [`Status::available`] |
+    /// | `{namespace}_pool_waiting` | gauge | See [`Status::waiting`] |
+    /// | `{namespace}_pool_objects_created_total` | counter | Number of objects created |
+    /// | `{namespace}_pool_objects_recycled_total` | counter | Number of objects recycled successfully |
+    /// | `{namespace}_pool_objects_detached_total` | counter | Number of objects removed from the [`Pool`] |
+    /// | `{namespace}_pool_timeouts_total` | counter | Number of timeouts with a `type` label being `wait`, `create` or `recycle` |
+    #[cfg(feature = "metrics")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "metrics")))]
+    pub fn register_metrics(&self, namespace: &str) {
+        if self
+            .inner
+            .metrics_exporter
+            .set(MetricsExporter::new(namespace))
+            .is_ok()
+        {
+            self.inner.update_metrics();
         }
     }
 
@@ -591,6 +1402,116 @@ impl<M: Manager, W: From<Object<M>>> Pool<M, W> {
     }
 }
 
+impl<M, W> Pool<M, W>
+where
+    M: Manager + 'static,
+    W: From<Object<M>>,
+{
+    /// Fills this [`Pool`] with new [`Object`]s until it contains at least
+    /// [`PoolConfig::min_idle`] objects and waits until they are ready.
+    ///
+    /// The [`Pool`] is also filled and replenished by a background task
+    /// which is started by [`PoolBuilder::build()`], so calling this method
+    /// is only needed to wait for the [`Pool`] to be ready, e.g. before
+    /// accepting requests. Calling this method does nothing if no
+    /// [`PoolConfig::min_idle`] is configured.
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    pub async fn warmup(&self) -> Result<(), PoolError<M::Error>> {
+        self.fill_min_idle().await
+    }
+
+    /// Spawns a background task removing idle [`Object`]s which exceeded
+    /// the limits of the given [`MaintenanceConfig`] using
+    /// [`Pool::retain()`].
+    ///
+    /// The task runs every [`MaintenanceConfig::interval`] until the
+    /// returned [`MaintenanceHandle`] is dropped or the [`Pool`] is closed or
+    /// dropped. Unlike [`PoolConfig::max_lifetime`] and
+    /// [`PoolConfig::idle_timeout`], which are only checked when an
+    /// [`Object`] is about to be recycled, this also shrinks a [`Pool`]
+    /// which isn't used at all.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PoolError::NoRuntimeSpecified`] if the [`Pool`] was built
+    /// without a [`Runtime`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn spawn_maintenance(
+        &self,
+        config: MaintenanceConfig,
+    ) -> Result<MaintenanceHandle, PoolError<M::Error>> {
+        let runtime = self.inner.runtime.ok_or(PoolError::NoRuntimeSpecified)?;
+        let weak = Arc::downgrade(&self.inner);
+        let task = runtime.spawn(async move {
+            loop {
+                runtime.sleep(config.interval).await;
+                let Some(inner) = weak.upgrade() else {
+                    break;
+                };
+                if inner.semaphore.is_closed() {
+                    break;
+                }
+                let pool = Pool::<M> {
+                    inner,
+                    _wrapper: PhantomData,
+                };
+                let _ = pool.retain(|_, metrics| {
+                    config.max_idle.map_or(true, |d| metrics.last_used() <= d)
+                        && config.max_lifetime.map_or(true, |d| metrics.age() <= d)
+                });
+            }
+        });
+        Ok(MaintenanceHandle { task })
+    }
+
+    /// Spawns the background task keeping [`PoolConfig::min_idle`]
+    /// [`Object`]s in this [`Pool`] and wakes it up for the initial fill.
+    fn spawn_min_idle_task(&self) {
+        let (Some(_), Some(runtime)) = (self.inner.config.min_idle, self.inner.runtime) else {
+            return;
+        };
+        let mut task = self.inner.min_idle_task.lock().unwrap();
+        let notify = self.inner.min_idle_notify.clone();
+        let weak = Arc::downgrade(&self.inner);
+        *task = Some(runtime.spawn(async move {
+            loop {
+                notify.notified().await;
+                let Some(inner) = weak.upgrade() else {
+                    break;
+                };
+                if inner.semaphore.is_closed() {
+                    break;
+                }
+                let pool = Pool::<M> {
+                    inner,
+                    _wrapper: PhantomData,
+                };
+                // Errors are ignored. The next removal triggers a new attempt.
+                let _ = pool.fill_min_idle().await;
+            }
+        }));
+        self.inner.notify_min_idle();
+    }
+}
+
+/// Handle of the background task started by [`Pool::spawn_maintenance()`].
+///
+/// The task is stopped when this handle is dropped.
+#[derive(Debug)]
+#[must_use]
+pub struct MaintenanceHandle {
+    task: SpawnHandle,
+}
+
+impl Drop for MaintenanceHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
 struct PoolInner<M: Manager> {
     manager: M,
     slots: Mutex<Slots<ObjectInner<M>>>,
@@ -602,13 +1523,41 @@ struct PoolInner<M: Manager> {
     config: PoolConfig,
     runtime: Option<Runtime>,
     hooks: hooks::Hooks<M>,
+    observer: Option<Arc<dyn PoolObserver<M>>>,
+    /// Semaphore shared with other [`Pool`]s limiting the number of
+    /// [`Object`]s borrowed from all of them.
+    shared_limit: Option<Arc<tokio::sync::Semaphore>>,
+    /// Number of slots acquired by [`Pool::get()`] and the time spent
+    /// waiting for them.
+    wait_count: AtomicU64,
+    wait_total_nanos: AtomicU64,
+    wait_last_nanos: AtomicU64,
+    /// Number of timeouts which occurred per [`TimeoutType`].
+    timeouts_wait: AtomicU64,
+    timeouts_create: AtomicU64,
+    timeouts_recycle: AtomicU64,
+    /// Number of [`Object`]s currently managed by the [`Pool`] and the
+    /// maximum thereof. Both are only modified while holding the lock of
+    /// `slots` but can be read without it.
+    size: AtomicUsize,
+    max_size: AtomicUsize,
+    /// Number of [`Object`]s created and detached since the [`Pool`] was
+    /// created.
+    objects_created: AtomicU64,
+    objects_detached: AtomicU64,
+    /// Wakes up the background task started by [`PoolBuilder::build()`].
+    min_idle_notify: Arc<Notify>,
+    min_idle_task: Mutex<Option<SpawnHandle>>,
+    /// Wakes up [`Pool::close_and_wait()`] once there are no more users.
+    drained_notify: Notify,
+    /// Metrics registered via [`Pool::register_metrics()`].
+    #[cfg(feature = "metrics")]
+    metrics_exporter: std::sync::OnceLock<MetricsExporter>,
 }
 
 #[derive(Debug)]
 struct Slots<T> {
     vec: VecDeque<T>,
-    size: usize,
-    max_size: usize,
 }
 
 // Implemented manually to avoid unnecessary trait bound on the struct.
@@ -621,59 +1570,251 @@ where
         f.debug_struct("PoolInner")
             .field("manager", &self.manager)
             .field("slots", &self.slots)
+            .field("size", &self.size)
+            .field("max_size", &self.max_size)
             .field("used", &self.users)
             .field("semaphore", &self.semaphore)
             .field("config", &self.config)
             .field("runtime", &self.runtime)
             .field("hooks", &self.hooks)
+            .field("observer", &self.observer.as_ref().map(|_| "PoolObserver"))
+            .field("shared_limit", &self.shared_limit)
             .finish()
     }
 }
 
+impl<M: Manager> Drop for PoolInner<M> {
+    fn drop(&mut self) {
+        // Stop the `min_idle` background task.
+        if let Some(task) = self.min_idle_task.get_mut().unwrap().take() {
+            task.abort();
+        }
+    }
+}
+
 impl<M: Manager> PoolInner<M> {
+    /// Returns the [`Status`] of the pool. The `size` and `max_size` are only
+    /// modified while holding the lock of the slots, so callers hold it for
+    /// a consistent snapshot.
+    fn status(&self) -> Status {
+        let users = self.users.load(Ordering::Relaxed);
+        let size = self.size.load(Ordering::Relaxed);
+        let (available, waiting) = if users < size {
+            (size - users, 0)
+        } else {
+            (0, users - size)
+        };
+        Status {
+            max_size: self.max_size.load(Ordering::Relaxed),
+            size,
+            available,
+            waiting,
+        }
+    }
+
+    /// Counts a timeout of the given [`TimeoutType`] and notifies the
+    /// [`PoolObserver`].
+    fn on_timeout(&self, timeout_type: TimeoutType) {
+        let counter = match timeout_type {
+            TimeoutType::Wait => &self.timeouts_wait,
+            TimeoutType::Create => &self.timeouts_create,
+            TimeoutType::Recycle => &self.timeouts_recycle,
+        };
+        let _ = counter.fetch_add(1, Ordering::Relaxed);
+        if let Some(observer) = &self.observer {
+            observer.on_timeout(timeout_type);
+        }
+        #[cfg(feature = "metrics")]
+        if let Some(exporter) = self.metrics_exporter.get() {
+            exporter.on_timeout(timeout_type);
+        }
+        trace_event!(self, ?timeout_type, "Timeout");
+    }
+
+    fn notify_min_idle(&self) {
+        if self.config.min_idle.is_some() {
+            self.min_idle_notify.notify_one();
+        }
+    }
     fn return_object(&self, mut inner: ObjectInner<M>) {
-        let _ = self.users.fetch_sub(1, Ordering::Relaxed);
         let mut slots = self.slots.lock().unwrap();
-        if slots.size <= slots.max_size {
+        if self.size.load(Ordering::Relaxed) <= self.max_size.load(Ordering::Relaxed) {
             slots.vec.push_back(inner);
             drop(slots);
             self.semaphore.add_permits(1);
         } else {
-            slots.size -= 1;
+            let _ = self.size.fetch_sub(1, Ordering::Relaxed);
             drop(slots);
-            self.manager.detach(&mut inner.obj);
+            self.detach(&mut inner.obj);
         }
+        self.release_user();
     }
     fn detach_object(&self, obj: &mut M::Type) {
-        let _ = self.users.fetch_sub(1, Ordering::Relaxed);
-        let mut slots = self.slots.lock().unwrap();
-        let add_permits = slots.size <= slots.max_size;
-        slots.size -= 1;
+        self.remove_slot();
+        self.detach(obj);
+        self.release_user();
+        self.notify_min_idle();
+    }
+    /// Decrements the size of the [`Pool`] by `n` objects.
+    fn remove_size(&self, n: usize) {
+        let _slots = self.slots.lock().unwrap();
+        let _ = self.size.fetch_sub(n, Ordering::Relaxed);
+    }
+    /// Frees the slot of an object which is removed from the [`Pool`].
+    fn remove_slot(&self) {
+        let slots = self.slots.lock().unwrap();
+        let add_permits =
+            self.size.load(Ordering::Relaxed) <= self.max_size.load(Ordering::Relaxed);
+        let _ = self.size.fetch_sub(1, Ordering::Relaxed);
         drop(slots);
         if add_permits {
             self.semaphore.add_permits(1);
         }
+    }
+    /// Decrements the number of users and wakes up
+    /// [`Pool::close_and_wait()`] once there are none left.
+    fn release_user(&self) {
+        if self.users.fetch_sub(1, Ordering::Relaxed) == 1 {
+            self.drained_notify.notify_waiters();
+        }
+        self.update_metrics();
+    }
+    /// Updates the gauges registered via [`Pool::register_metrics()`]. This
+    /// must not be called while holding the lock of the slots.
+    #[cfg(feature = "metrics")]
+    fn update_metrics(&self) {
+        if let Some(exporter) = self.metrics_exporter.get() {
+            let _slots = self.slots.lock().unwrap();
+            exporter.set_status(self.status());
+        }
+    }
+    #[cfg(not(feature = "metrics"))]
+    #[inline(always)]
+    fn update_metrics(&self) {}
+    #[cfg(not(target_arch = "wasm32"))]
+    fn record_wait(&self, duration: Duration) {
+        let nanos = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        let _ = self.wait_count.fetch_add(1, Ordering::Relaxed);
+        let _ = self.wait_total_nanos.fetch_add(nanos, Ordering::Relaxed);
+        self.wait_last_nanos.store(nanos, Ordering::Relaxed);
+    }
+    /// Creates a new object retrying failed attempts according to the
+    /// [`PoolConfig::create_retry`] policy.
+    async fn create(&self) -> Result<M::Type, CreateError<M::Error>> {
+        let mut attempts = 0;
+        loop {
+            let result = catch_unwind(self.manager.create())
+                .await
+                .map_err(CreateError::Panic)?;
+            attempts += 1;
+            match (result, &self.config.create_retry) {
+                (Err(_), Some(retry)) if attempts <= retry.max_retries => {
+                    // `PoolBuilder::build()` rejects a retry policy without
+                    // a runtime, so the delay can always be applied.
+                    self.runtime
+                        .expect("create_retry requires a runtime which is checked by build()")
+                        .sleep(retry.delay(attempts - 1))
+                        .await;
+                }
+                (result, ..) => return result.map_err(CreateError::Backend),
+            }
+        }
+    }
+    /// Detaches the given object from the [`Manager`] and notifies the
+    /// [`PoolObserver`].
+    fn detach(&self, obj: &mut M::Type) {
         self.manager.detach(obj);
+        if let Some(observer) = &self.observer {
+            observer.on_detach(obj);
+        }
+        self.count_detached();
+    }
+    /// Counts an object which was detached from the [`Pool`].
+    fn count_detached(&self) {
+        let _ = self.objects_detached.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "metrics")]
+        if let Some(exporter) = self.metrics_exporter.get() {
+            exporter.detached.increment(1);
+        }
+    }
+    async fn apply_timeout<O, E>(
+        &self,
+        timeout_type: TimeoutType,
+        duration: Option<Duration>,
+        future: impl Future<Output = Result<O, impl Into<PoolError<E>>>>,
+    ) -> Result<O, PoolError<E>> {
+        match (self.runtime, duration) {
+            (_, None) => future.await.map_err(Into::into),
+            (Some(runtime), Some(duration)) => runtime
+                .timeout(duration, future)
+                .await
+                .ok_or_else(|| {
+                    self.on_timeout(timeout_type);
+                    PoolError::Timeout(timeout_type)
+                })?
+                .map_err(Into::into),
+            (None, Some(_)) => Err(PoolError::NoRuntimeSpecified),
+        }
     }
 }
 
-async fn apply_timeout<O, E>(
-    runtime: Option<Runtime>,
-    timeout_type: TimeoutType,
-    duration: Option<Duration>,
-    future: impl Future<Output = Result<O, impl Into<PoolError<E>>>>,
-) -> Result<O, PoolError<E>> {
-    match (runtime, duration) {
-        (_, None) => future.await.map_err(Into::into),
-        (Some(runtime), Some(duration)) => runtime
-            .timeout(duration, future)
-            .await
-            .ok_or(PoolError::Timeout(timeout_type))?
-            .map_err(Into::into),
-        (None, Some(_)) => Err(PoolError::NoRuntimeSpecified),
+/// Removes the object which was most recently created or recycled from the
+/// given idle objects.
+fn pop_most_recently_used<M: Manager>(
+    vec: &mut VecDeque<ObjectInner<M>>,
+) -> Option<ObjectInner<M>> {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        // `max_by_key` returns the last element on ties which prefers the
+        // most recently returned object just like `QueueMode::Lifo` does.
+        let index = vec
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, obj)| obj.metrics.recycled.unwrap_or(obj.metrics.created))
+            .map(|(index, _)| index)?;
+        vec.remove(index)
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        vec.pop_back()
+    }
+}
+
+/// Limits the given `timeout` to the time remaining until the `deadline`.
+fn limit_timeout(timeout: Option<Duration>, deadline: Option<Instant>) -> Option<Duration> {
+    match deadline {
+        Some(deadline) => {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            Some(timeout.map_or(remaining, |timeout| timeout.min(remaining)))
+        }
+        None => timeout,
     }
 }
 
+/// Limits all the given `timeouts` to the time remaining until the
+/// `deadline`.
+fn limit_timeouts(timeouts: &Timeouts, deadline: Option<Instant>) -> Timeouts {
+    Timeouts {
+        wait: limit_timeout(timeouts.wait, deadline),
+        create: limit_timeout(timeouts.create, deadline),
+        recycle: limit_timeout(timeouts.recycle, deadline),
+    }
+}
+
+/// Runs the given `future` and catches any panic which occurs while polling
+/// it.
+async fn catch_unwind<F: Future>(future: F) -> Result<F::Output, Box<dyn Any + Send + 'static>> {
+    let mut future = Box::pin(future);
+    poll_fn(
+        |cx| match panic::catch_unwind(AssertUnwindSafe(|| future.as_mut().poll(cx))) {
+            Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+            Ok(Poll::Pending) => Poll::Pending,
+            Err(payload) => Poll::Ready(Err(payload)),
+        },
+    )
+    .await
+}
+
 #[derive(Debug)]
 /// This is the result returned by `Pool::retain`
 pub struct RetainResult<T> {
@@ -0,0 +1,35 @@
+//! Observer allowing to get notified about events happening inside a pool.
+
+use super::{Manager, Metrics, TimeoutType};
+
+/// Observer which gets notified about lifecycle events of a [`Pool`].
+///
+/// All methods have an empty default implementation so only the events of
+/// interest need to be implemented. The methods are called synchronously
+/// from within the [`Pool`] and should therefore return quickly.
+///
+/// Observers are attached using the [`PoolBuilder::observer()`] method.
+///
+/// [`Pool`]: super::Pool
+/// [`PoolBuilder::observer()`]: super::PoolBuilder::observer
+pub trait PoolObserver<M: Manager>: Sync + Send {
+    /// Called after a new object has been created and all `post_create`
+    /// hooks succeeded.
+    fn on_create(&self, _obj: &M::Type) {}
+
+    /// Called after an object has been recycled successfully.
+    fn on_recycle(&self, _obj: &M::Type, _metrics: &Metrics) {}
+
+    /// Called after an object has been detached from the [`Pool`].
+    ///
+    /// [`Pool`]: super::Pool
+    fn on_detach(&self, _obj: &M::Type) {}
+
+    /// Called when a timeout occurred.
+    fn on_timeout(&self, _timeout_type: TimeoutType) {}
+
+    /// Called after an object has been handed out by the [`Pool`].
+    ///
+    /// [`Pool`]: super::Pool
+    fn on_acquire(&self, _metrics: &Metrics) {}
+}
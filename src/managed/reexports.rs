@@ -15,7 +15,10 @@
 //! ```
 
 pub use crate::{
-    managed::{Metrics, PoolConfig, Status, Timeouts},
+    managed::{
+        Extensions, GetOptions, MaintenanceConfig, MaintenanceHandle, Metrics, ObjectCounts,
+        PoolConfig, RetryConfig, Status, TimeoutCounts, Timeouts, WaitStats,
+    },
     Runtime,
 };
 
@@ -48,8 +51,30 @@ macro_rules! managed_reexports {
         #[doc=concat!("Type alias for using [`deadpool::managed::Hook`] with [`", $crate_name, "`].")]
         pub type Hook = deadpool::managed::Hook<$Manager>;
 
+        #[doc=concat!("Type alias for using [`deadpool::managed::PreCreateHook`] with [`", $crate_name, "`].")]
+        pub type PreCreateHook = deadpool::managed::PreCreateHook<$Manager>;
+
         #[doc=concat!("Type alias for using [`deadpool::managed::HookError`] with [`", $crate_name, "`].")]
         pub type HookError = deadpool::managed::HookError<$Error>;
 
+        #[doc=concat!("Type alias for using [`deadpool::managed::HookResult`] with [`", $crate_name, "`].")]
+        pub type HookResult = deadpool::managed::HookResult<$Error>;
+
+        #[doc=concat!("Type alias for using [`deadpool::managed::HookFuture`] with [`", $crate_name, "`].")]
+        pub type HookFuture<'a> = deadpool::managed::HookFuture<'a, $Error>;
+
+        #[doc=concat!("Type alias for using [`deadpool::managed::ReplaceHookResult`] with [`", $crate_name, "`].")]
+        pub type ReplaceHookResult = deadpool::managed::ReplaceHookResult<
+            <$Manager as deadpool::managed::Manager>::Type,
+            $Error,
+        >;
+
+        #[doc=concat!("Type alias for using [`deadpool::managed::ReplaceHookFuture`] with [`", $crate_name, "`].")]
+        pub type ReplaceHookFuture<'a> = deadpool::managed::ReplaceHookFuture<
+            'a,
+            <$Manager as deadpool::managed::Manager>::Type,
+            $Error,
+        >;
+
     };
 }
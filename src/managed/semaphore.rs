@@ -0,0 +1,272 @@
+//! Semaphore limiting the number of [`Object`]s handed out by a [`Pool`].
+//!
+//! Depending on the configured [`WaiterMode`] either [`tokio`]'s semaphore
+//! (first in first out) or a semaphore waking up the most recent waiter
+//! first (last in first out) is used.
+//!
+//! [`Object`]: super::Object
+//! [`Pool`]: super::Pool
+
+use std::{
+    future::Future,
+    mem,
+    pin::Pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Waker},
+};
+
+use tokio::sync::{SemaphorePermit, TryAcquireError};
+
+use super::WaiterMode;
+
+/// Error returned when acquiring a permit from a closed [`Semaphore`].
+#[derive(Debug)]
+pub(crate) struct AcquireError;
+
+#[derive(Debug)]
+pub(crate) enum Semaphore {
+    Fifo(tokio::sync::Semaphore),
+    Lifo(LifoSemaphore),
+}
+
+impl Semaphore {
+    pub(crate) fn new(mode: WaiterMode, permits: usize) -> Self {
+        match mode {
+            WaiterMode::Fifo => Self::Fifo(tokio::sync::Semaphore::new(permits)),
+            WaiterMode::Lifo => Self::Lifo(LifoSemaphore::new(permits)),
+        }
+    }
+
+    pub(crate) async fn acquire(&self) -> Result<Permit<'_>, AcquireError> {
+        match self {
+            Self::Fifo(s) => s
+                .acquire()
+                .await
+                .map(Permit::Fifo)
+                .map_err(|_| AcquireError),
+            Self::Lifo(s) => s.acquire().await.map(Permit::Lifo),
+        }
+    }
+
+    pub(crate) fn try_acquire(&self) -> Result<Permit<'_>, TryAcquireError> {
+        match self {
+            Self::Fifo(s) => s.try_acquire().map(Permit::Fifo),
+            Self::Lifo(s) => s.try_acquire().map(Permit::Lifo),
+        }
+    }
+
+    pub(crate) fn add_permits(&self, n: usize) {
+        match self {
+            Self::Fifo(s) => s.add_permits(n),
+            Self::Lifo(s) => s.add_permits(n),
+        }
+    }
+
+    pub(crate) fn close(&self) {
+        match self {
+            Self::Fifo(s) => s.close(),
+            Self::Lifo(s) => s.close(),
+        }
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        match self {
+            Self::Fifo(s) => s.is_closed(),
+            Self::Lifo(s) => s.is_closed(),
+        }
+    }
+}
+
+/// Permit acquired from a [`Semaphore`]. It is returned to the
+/// [`Semaphore`] when being dropped.
+#[derive(Debug)]
+pub(crate) enum Permit<'a> {
+    Fifo(SemaphorePermit<'a>),
+    Lifo(LifoPermit<'a>),
+}
+
+impl Permit<'_> {
+    /// Forgets this permit without returning it to the [`Semaphore`].
+    pub(crate) fn forget(self) {
+        match self {
+            Self::Fifo(p) => p.forget(),
+            Self::Lifo(p) => mem::forget(p),
+        }
+    }
+}
+
+/// Semaphore which hands out permits to the most recent waiter first.
+#[derive(Debug)]
+pub(crate) struct LifoSemaphore {
+    state: Mutex<LifoState>,
+}
+
+#[derive(Debug)]
+struct LifoState {
+    permits: usize,
+    closed: bool,
+    /// Waiters ordered from the oldest to the most recent one.
+    waiters: Vec<Arc<Waiter>>,
+}
+
+#[derive(Debug)]
+struct Waiter {
+    /// Only accessed while holding the lock of the [`LifoState`].
+    inner: Mutex<WaiterInner>,
+}
+
+#[derive(Debug)]
+struct WaiterInner {
+    waker: Option<Waker>,
+    assigned: bool,
+}
+
+impl LifoSemaphore {
+    fn new(permits: usize) -> Self {
+        Self {
+            state: Mutex::new(LifoState {
+                permits,
+                closed: false,
+                waiters: Vec::new(),
+            }),
+        }
+    }
+
+    fn acquire(&self) -> Acquire<'_> {
+        Acquire {
+            semaphore: self,
+            waiter: None,
+        }
+    }
+
+    fn try_acquire(&self) -> Result<LifoPermit<'_>, TryAcquireError> {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            Err(TryAcquireError::Closed)
+        } else if state.permits > 0 {
+            state.permits -= 1;
+            Ok(LifoPermit { semaphore: self })
+        } else {
+            Err(TryAcquireError::NoPermits)
+        }
+    }
+
+    fn add_permits(&self, n: usize) {
+        let mut state = self.state.lock().unwrap();
+        state.permits += n;
+        let wakers = state.assign_permits();
+        drop(state);
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        let wakers = state
+            .waiters
+            .drain(..)
+            .filter_map(|waiter| waiter.inner.lock().unwrap().waker.take())
+            .collect::<Vec<_>>();
+        drop(state);
+        wakers.into_iter().for_each(Waker::wake);
+    }
+
+    fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+}
+
+impl LifoState {
+    /// Hands out the available permits to the most recent waiters and
+    /// returns their [`Waker`]s.
+    fn assign_permits(&mut self) -> Vec<Waker> {
+        let mut wakers = Vec::new();
+        while self.permits > 0 {
+            let Some(waiter) = self.waiters.pop() else {
+                break;
+            };
+            self.permits -= 1;
+            let mut inner = waiter.inner.lock().unwrap();
+            inner.assigned = true;
+            wakers.extend(inner.waker.take());
+        }
+        wakers
+    }
+}
+
+/// Future returned by [`LifoSemaphore::acquire()`].
+struct Acquire<'a> {
+    semaphore: &'a LifoSemaphore,
+    waiter: Option<Arc<Waiter>>,
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = Result<LifoPermit<'a>, AcquireError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let semaphore = self.semaphore;
+        let mut state = semaphore.state.lock().unwrap();
+        if let Some(waiter) = &self.waiter {
+            let mut inner = waiter.inner.lock().unwrap();
+            if inner.assigned {
+                drop(inner);
+                self.waiter = None;
+                return Poll::Ready(Ok(LifoPermit { semaphore }));
+            }
+            if state.closed {
+                drop(inner);
+                self.waiter = None;
+                return Poll::Ready(Err(AcquireError));
+            }
+            inner.waker = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        if state.closed {
+            return Poll::Ready(Err(AcquireError));
+        }
+        if state.permits > 0 {
+            state.permits -= 1;
+            return Poll::Ready(Ok(LifoPermit { semaphore }));
+        }
+        let waiter = Arc::new(Waiter {
+            inner: Mutex::new(WaiterInner {
+                waker: Some(cx.waker().clone()),
+                assigned: false,
+            }),
+        });
+        state.waiters.push(waiter.clone());
+        drop(state);
+        self.waiter = Some(waiter);
+        Poll::Pending
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(waiter) = self.waiter.take() else {
+            return;
+        };
+        let mut state = self.semaphore.state.lock().unwrap();
+        if waiter.inner.lock().unwrap().assigned {
+            // The permit was assigned but never picked up. Pass it on.
+            state.permits += 1;
+            let wakers = state.assign_permits();
+            drop(state);
+            wakers.into_iter().for_each(Waker::wake);
+        } else {
+            state.waiters.retain(|w| !Arc::ptr_eq(w, &waiter));
+        }
+    }
+}
+
+/// Permit acquired from a [`LifoSemaphore`].
+#[derive(Debug)]
+pub(crate) struct LifoPermit<'a> {
+    semaphore: &'a LifoSemaphore,
+}
+
+impl Drop for LifoPermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.add_permits(1);
+    }
+}
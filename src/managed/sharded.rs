@@ -0,0 +1,148 @@
+//! Pool distributing its objects across multiple backing [`Pool`]s.
+//!
+//! This is useful when the same data is available via multiple servers
+//! (e.g. read replicas of a database) and the load should be spread evenly
+//! among them.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use super::{Manager, Object, Pool, PoolError, Status};
+
+/// Strategy used by a [`ShardedPool`] for selecting the shard an object is
+/// retrieved from.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ShardSelection {
+    /// Pick the shard which can provide the most objects without waiting.
+    ///
+    /// Ties are broken in a round-robin fashion.
+    #[default]
+    LeastLoaded,
+
+    /// Pick the shards one after another.
+    RoundRobin,
+}
+
+/// Generic object and connection pool which holds multiple [`Pool`]s (aka.
+/// shards) and distributes the calls to [`ShardedPool::get()`] among them.
+///
+/// Just like a [`Pool`] this struct can be cloned and transferred across
+/// thread boundaries and uses reference counting for its internal state.
+pub struct ShardedPool<M: Manager, W: From<Object<M>> = Object<M>> {
+    shards: Arc<[Pool<M, W>]>,
+    selection: ShardSelection,
+    next: Arc<AtomicUsize>,
+}
+
+// Implemented manually to avoid unnecessary trait bound on `W` type parameter.
+impl<M, W> fmt::Debug for ShardedPool<M, W>
+where
+    M: fmt::Debug + Manager,
+    M::Type: fmt::Debug,
+    W: From<Object<M>>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardedPool")
+            .field("shards", &self.shards)
+            .field("selection", &self.selection)
+            .finish()
+    }
+}
+
+impl<M: Manager, W: From<Object<M>>> Clone for ShardedPool<M, W> {
+    fn clone(&self) -> Self {
+        Self {
+            shards: self.shards.clone(),
+            selection: self.selection,
+            next: self.next.clone(),
+        }
+    }
+}
+
+impl<M: Manager, W: From<Object<M>>> ShardedPool<M, W> {
+    /// Creates a new [`ShardedPool`] from the given `shards` using the given
+    /// [`ShardSelection`] strategy.
+    ///
+    /// # Panics
+    ///
+    /// If no `shards` are given.
+    #[must_use]
+    pub fn new(shards: Vec<Pool<M, W>>, selection: ShardSelection) -> Self {
+        assert!(
+            !shards.is_empty(),
+            "ShardedPool requires at least one shard"
+        );
+        Self {
+            shards: shards.into(),
+            selection,
+            next: Arc::new(AtomicUsize::new(0)),
+        }
+    }
+
+    /// Retrieves an [`Object`] from one of the shards selected using the
+    /// [`ShardSelection`] strategy of this [`ShardedPool`].
+    ///
+    /// # Errors
+    ///
+    /// See [`PoolError`] for details.
+    pub async fn get(&self) -> Result<W, PoolError<M::Error>> {
+        self.shards[self.select()].get().await
+    }
+
+    /// Returns all the shards of this [`ShardedPool`].
+    #[must_use]
+    pub fn shards(&self) -> &[Pool<M, W>] {
+        &self.shards
+    }
+
+    /// Retrieves the [`Status`] of this [`ShardedPool`] which is the sum of
+    /// the [`Status`]es of all its shards.
+    #[must_use]
+    pub fn status(&self) -> Status {
+        self.shards.iter().map(Pool::status).fold(
+            Status {
+                max_size: 0,
+                size: 0,
+                available: 0,
+                waiting: 0,
+            },
+            |total, status| Status {
+                max_size: total.max_size + status.max_size,
+                size: total.size + status.size,
+                available: total.available + status.available,
+                waiting: total.waiting + status.waiting,
+            },
+        )
+    }
+
+    /// Closes all the shards of this [`ShardedPool`]. See [`Pool::close()`].
+    pub fn close(&self) {
+        for shard in self.shards.iter() {
+            shard.close();
+        }
+    }
+
+    /// Returns the index of the shard the next object is retrieved from.
+    fn select(&self) -> usize {
+        let len = self.shards.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed) % len;
+        match self.selection {
+            ShardSelection::RoundRobin => start,
+            ShardSelection::LeastLoaded => (0..len)
+                .map(|offset| (start + offset) % len)
+                .min_by_key(|&index| {
+                    let status = self.shards[index].status();
+                    // Number of objects which can be handed out without
+                    // waiting, either idle ones or ones not created yet.
+                    let free = status.max_size.saturating_sub(status.size) + status.available;
+                    status.waiting as isize - free as isize
+                })
+                .unwrap_or(start),
+        }
+    }
+}
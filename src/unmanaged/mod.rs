@@ -45,6 +45,8 @@ use tokio::sync::{Semaphore, TryAcquireError};
 
 pub use crate::Status;
 
+use crate::dropguard::DropGuard;
+
 pub use self::{config::PoolConfig, errors::PoolError};
 
 /// Wrapper around the actual pooled object which implements [`Deref`],
@@ -69,8 +71,7 @@ impl<T> Object<T> {
     #[must_use]
     pub fn take(mut this: Self) -> T {
         if let Some(pool) = this.pool.upgrade() {
-            let _ = pool.size.fetch_sub(1, Ordering::Relaxed);
-            pool.size_semaphore.add_permits(1);
+            pool.release_size(1);
         }
         this.obj.take().unwrap()
     }
@@ -162,6 +163,7 @@ impl<T> Pool<T> {
             inner: Arc::new(PoolInner {
                 config: *config,
                 queue: Mutex::new(Vec::with_capacity(config.max_size)),
+                max_size: AtomicUsize::new(config.max_size),
                 size: AtomicUsize::new(0),
                 size_semaphore: Semaphore::new(config.max_size),
                 available: AtomicIsize::new(0),
@@ -213,6 +215,11 @@ impl<T> Pool<T> {
     /// See [`PoolError`] for details.
     pub async fn timeout_get(&self, timeout: Option<Duration>) -> Result<Object<T>, PoolError> {
         let inner = self.inner.as_ref();
+        // Count this call as waiting until an object has been acquired.
+        let _ = inner.available.fetch_sub(1, Ordering::Relaxed);
+        let available_guard = DropGuard(|| {
+            let _ = inner.available.fetch_add(1, Ordering::Relaxed);
+        });
         let permit = match (timeout, inner.config.runtime) {
             (None, _) => inner
                 .semaphore
@@ -237,7 +244,7 @@ impl<T> Pool<T> {
             queue.pop().unwrap()
         };
         permit.forget();
-        let _ = inner.available.fetch_sub(1, Ordering::Relaxed);
+        available_guard.disarm();
         Ok(Object {
             pool: Arc::downgrade(&self.inner),
             obj: Some(obj),
@@ -316,6 +323,87 @@ impl<T> Pool<T> {
         self.timeout_get(timeout).await.map(Object::take)
     }
 
+    /// Resizes this [`Pool`] by changing its `max_size`.
+    ///
+    /// Growing the [`Pool`] allows tasks waiting in [`Pool::add()`] to
+    /// continue. Shrinking the [`Pool`] removes idle [`Object`]s until the
+    /// new `max_size` is reached. [`Object`]s which are currently in use are
+    /// not removed, instead no new [`Object`]s can be added until enough of
+    /// them have been removed.
+    ///
+    /// If the [`Pool`] is closed this method does nothing.
+    pub fn resize(&self, max_size: usize) {
+        let inner = self.inner.as_ref();
+        if inner.is_closed() {
+            return;
+        }
+        let old_max_size = inner.max_size.swap(max_size, Ordering::Relaxed);
+        let size = inner.size.load(Ordering::Relaxed);
+        let old_permits = old_max_size.saturating_sub(size);
+        let permits = max_size.saturating_sub(size);
+        // grow pool
+        if permits > old_permits {
+            inner.size_semaphore.add_permits(permits - old_permits);
+        }
+        // shrink pool
+        if max_size < old_max_size {
+            for _ in permits..old_permits {
+                match inner.size_semaphore.try_acquire() {
+                    Ok(permit) => permit.forget(),
+                    Err(_) => break,
+                }
+            }
+            while inner.size.load(Ordering::Relaxed) > max_size {
+                let Ok(permit) = inner.semaphore.try_acquire() else {
+                    break;
+                };
+                permit.forget();
+                drop(inner.queue.lock().unwrap().pop());
+                let _ = inner.available.fetch_sub(1, Ordering::Relaxed);
+                let _ = inner.size.fetch_sub(1, Ordering::Relaxed);
+            }
+        }
+    }
+
+    /// Retains only the idle [`Object`]s specified by the given function and
+    /// drops all the others.
+    ///
+    /// This function is typically used to remove objects from the [`Pool`]
+    /// whose state has become stale. [`Object`]s which are currently in use
+    /// are not passed to the function.
+    ///
+    /// **Caution:** This function blocks the entire [`Pool`] while it is
+    /// running. Therefore the given function should not block.
+    pub fn retain(&self, mut f: impl FnMut(&T) -> bool) {
+        let inner = self.inner.as_ref();
+        let mut removed = 0;
+        {
+            let mut queue = inner.queue.lock().unwrap();
+            queue.retain(|obj| {
+                if f(obj) {
+                    return true;
+                }
+                // Objects are only removed if a permit can be acquired as
+                // every permit of `semaphore` belongs to an object in the
+                // queue.
+                match inner.semaphore.try_acquire() {
+                    Ok(permit) => {
+                        permit.forget();
+                        removed += 1;
+                        false
+                    }
+                    Err(_) => true,
+                }
+            });
+        }
+        if removed > 0 {
+            let _ = inner
+                .available
+                .fetch_sub(removed as isize, Ordering::Relaxed);
+            inner.release_size(removed);
+        }
+    }
+
     /// Closes this [`Pool`].
     ///
     /// All current and future tasks waiting for [`Object`]s will return
@@ -334,7 +422,7 @@ impl<T> Pool<T> {
     /// Retrieves [`Status`] of this [`Pool`].
     #[must_use]
     pub fn status(&self) -> Status {
-        let max_size = self.inner.config.max_size;
+        let max_size = self.inner.max_size.load(Ordering::Relaxed);
         let size = self.inner.size.load(Ordering::Relaxed);
         let available = self.inner.available.load(Ordering::Relaxed);
         Status {
@@ -354,8 +442,11 @@ impl<T> Pool<T> {
 struct PoolInner<T> {
     config: PoolConfig,
     queue: Mutex<Vec<T>>,
+    /// Current maximum size which can be changed via [`Pool::resize()`].
+    max_size: AtomicUsize,
     size: AtomicUsize,
-    /// This semaphore has as many permits as `max_size - size`. Every time
+    /// This semaphore has as many permits as `max_size - size` (or none if
+    /// `size` exceeds `max_size` after shrinking the [`Pool`]). Every time
     /// an [`Object`] is added to the [`Pool`] a permit is removed from the
     /// semaphore and every time an [`Object`] is removed a permit is returned
     /// back.
@@ -370,6 +461,17 @@ struct PoolInner<T> {
 }
 
 impl<T> PoolInner<T> {
+    /// Reduces the size of this [`Pool`] by `n` returning permits to the
+    /// `size_semaphore` as long as `max_size` isn't exceeded.
+    fn release_size(&self, n: usize) {
+        let size = self.size.fetch_sub(n, Ordering::Relaxed) - n;
+        let max_size = self.max_size.load(Ordering::Relaxed);
+        let permits = max_size.saturating_sub(size).min(n);
+        if permits > 0 {
+            self.size_semaphore.add_permits(permits);
+        }
+    }
+
     /// Cleans up internals of this [`Pool`].
     ///
     /// This method is called after closing the [`Pool`] and whenever an
@@ -414,6 +516,7 @@ where
             inner: Arc::new(PoolInner {
                 queue: Mutex::new(queue),
                 config: PoolConfig::new(len),
+                max_size: AtomicUsize::new(len),
                 size: AtomicUsize::new(len),
                 size_semaphore: Semaphore::new(0),
                 available: AtomicIsize::new(len.try_into().unwrap()),
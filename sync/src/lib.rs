@@ -24,16 +24,23 @@ pub mod reexports;
 
 use std::{
     any::Any,
+    convert::Infallible,
     fmt,
     ops::{Deref, DerefMut},
+    panic,
     sync::{Arc, Mutex, MutexGuard, PoisonError, TryLockError},
+    time::Duration,
 };
 
 use deadpool_runtime::{Runtime, SpawnBlockingError};
 
 /// Possible errors returned when [`SyncWrapper::interact()`] fails.
+///
+/// The `Backend` variant is only used by [`SyncWrapper::interact_backend()`]
+/// and can't occur when using the other methods as `E` defaults to
+/// [`Infallible`].
 #[derive(Debug)]
-pub enum InteractError {
+pub enum InteractError<E = Infallible> {
     /// Provided callback has panicked.
     Panic(Box<dyn Any + Send + 'static>),
 
@@ -41,18 +48,35 @@ pub enum InteractError {
     /// reasons but you should never actually be able to get this as a
     /// return value when calling `SyncWrapper::interact`.
     Aborted,
+
+    /// Callback didn't finish in time when calling
+    /// [`SyncWrapper::interact_timeout()`].
+    Timeout,
+
+    /// Callback returned an error when calling
+    /// [`SyncWrapper::interact_backend()`].
+    Backend(E),
 }
 
-impl fmt::Display for InteractError {
+impl<E: fmt::Display> fmt::Display for InteractError<E> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Panic(_) => write!(f, "Panic"),
             Self::Aborted => write!(f, "Aborted"),
+            Self::Timeout => write!(f, "Timeout"),
+            Self::Backend(e) => write!(f, "Backend: {}", e),
         }
     }
 }
 
-impl std::error::Error for InteractError {}
+impl<E: std::error::Error + 'static> std::error::Error for InteractError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Backend(e) => Some(e),
+            _ => None,
+        }
+    }
+}
 
 /// Wrapper for objects which only provides blocking functions that need to be
 /// called on a separate thread.
@@ -68,16 +92,17 @@ where
     runtime: Runtime,
 }
 
-// Implemented manually to avoid unnecessary trait bound on `E` type parameter.
+// Implemented manually to avoid a `T: Debug` bound. Many wrapped objects
+// (e.g. `diesel` connections) don't implement `Debug` which would otherwise
+// make it impossible to print pools of them.
 impl<T> fmt::Debug for SyncWrapper<T>
 where
-    T: fmt::Debug + Send + 'static,
+    T: Send + 'static,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("SyncWrapper")
-            .field("obj", &self.obj)
             .field("runtime", &self.runtime)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -92,11 +117,9 @@ where
         E: Send + 'static,
     {
         let result = match runtime.spawn_blocking(f).await {
-            // FIXME: Panicking when the creation panics is not nice.
-            // In order to handle this properly the Manager::create
-            // methods needs to support a custom error enum which
-            // supports a Panic variant.
-            Err(SpawnBlockingError::Panic(e)) => panic!("{:?}", e),
+            // Resume the panic with its original payload. The pool catches
+            // panics of `Manager::create` and turns them into an error.
+            Err(SpawnBlockingError::Panic(e)) => panic::resume_unwind(e),
             Ok(obj) => obj,
         };
         result.map(|obj| Self {
@@ -130,6 +153,59 @@ where
             .map_err(|SpawnBlockingError::Panic(p)| InteractError::Panic(p))?
     }
 
+    /// Interacts with the underlying object using a fallible closure.
+    ///
+    /// This works just like [`SyncWrapper::interact()`] but errors returned
+    /// by the closure are returned as [`InteractError::Backend`] instead of
+    /// being wrapped inside the result.
+    pub async fn interact_backend<F, R, E>(&self, f: F) -> Result<R, InteractError<E>>
+    where
+        F: FnOnce(&mut T) -> Result<R, E> + Send + 'static,
+        R: Send + 'static,
+        E: Send + 'static,
+    {
+        match self.interact(f).await {
+            Ok(result) => result.map_err(InteractError::Backend),
+            Err(InteractError::Panic(p)) => Err(InteractError::Panic(p)),
+            Err(InteractError::Aborted) => Err(InteractError::Aborted),
+            Err(InteractError::Timeout) => Err(InteractError::Timeout),
+            Err(InteractError::Backend(e)) => match e {},
+        }
+    }
+
+    /// Interacts with the underlying object without being able to modify it.
+    ///
+    /// This works just like [`SyncWrapper::interact()`] but only passes a
+    /// shared reference to the closure making it clear that it is read-only.
+    /// **Note:** The object is still locked while the closure runs, so
+    /// multiple calls are not executed concurrently.
+    pub async fn interact_ref<F, R>(&self, f: F) -> Result<R, InteractError>
+    where
+        F: FnOnce(&T) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.interact(move |obj| f(obj)).await
+    }
+
+    /// Interacts with the underlying object just like
+    /// [`SyncWrapper::interact()`] but returns [`InteractError::Timeout`] if
+    /// the closure doesn't finish within the given `duration`.
+    ///
+    /// **Important:** The closure runs on a blocking thread which can't be
+    /// canceled. When the timeout is reached only awaiting the result is
+    /// stopped while the closure keeps running in the background and keeps
+    /// the object locked until it finishes.
+    pub async fn interact_timeout<F, R>(&self, duration: Duration, f: F) -> Result<R, InteractError>
+    where
+        F: FnOnce(&mut T) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.runtime
+            .timeout(duration, self.interact(f))
+            .await
+            .ok_or(InteractError::Timeout)?
+    }
+
     /// Indicates whether the underlying [`Mutex`] has been poisoned.
     ///
     /// This happens when a panic occurs while interacting with the object.
@@ -137,8 +213,29 @@ where
         self.obj.is_poisoned()
     }
 
+    /// Returns the [`Runtime`] used for running blocking closures.
+    ///
+    /// This makes it possible to use the same [`Runtime`] for related work,
+    /// e.g. applying timeouts or spawning further blocking tasks.
+    pub fn runtime(&self) -> Runtime {
+        self.runtime
+    }
+
     /// Lock the underlying mutex and return a guard for the inner
     /// object.
+    ///
+    /// This can be used to run several blocking operations in a row without
+    /// calling [`SyncWrapper::interact()`] for each of them. **Important:**
+    /// Blocking operations on the returned [`SyncGuard`] must not be
+    /// executed on the async runtime directly:
+    ///
+    /// ```rust,ignore
+    /// tokio::task::block_in_place(|| {
+    ///     let mut guard = wrapper.lock().unwrap();
+    ///     first_operation(&mut guard);
+    ///     second_operation(&mut guard);
+    /// });
+    /// ```
     pub fn lock(&self) -> Result<SyncGuard<'_, T>, PoisonError<MutexGuard<'_, Option<T>>>> {
         self.obj.lock().map(SyncGuard)
     }
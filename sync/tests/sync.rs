@@ -1,6 +1,8 @@
-use deadpool::managed::{Manager, Metrics, Pool, RecycleResult};
+use std::{thread, time::Duration};
+
+use deadpool::managed::{Manager, Metrics, Pool, PoolError, RecycleResult};
 use deadpool_runtime::Runtime;
-use deadpool_sync::SyncWrapper;
+use deadpool_sync::{InteractError, SyncWrapper};
 
 struct Computer {
     pub answer: usize,
@@ -37,3 +39,111 @@ async fn post_recycle() {
     let guard = obj.lock().unwrap();
     assert_eq!(guard.answer, 42);
 }
+
+struct PanicManager {}
+
+impl Manager for PanicManager {
+    type Type = SyncWrapper<Computer>;
+    type Error = ();
+
+    async fn create(&self) -> Result<Self::Type, Self::Error> {
+        SyncWrapper::new(Runtime::Tokio1, || panic!("create failed")).await
+    }
+
+    async fn recycle(
+        &self,
+        _obj: &mut Self::Type,
+        _metrics: &Metrics,
+    ) -> RecycleResult<Self::Error> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn create_panic() {
+    let pool = Pool::<PanicManager>::builder(PanicManager {})
+        .max_size(1)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::CreatePanic(msg)) if msg == "create failed"
+    ));
+    assert_eq!(pool.status().size, 0);
+    assert!(matches!(pool.get().await, Err(PoolError::CreatePanic(_))));
+}
+
+#[tokio::test]
+async fn current_thread_runtime() {
+    let obj = SyncWrapper::new(Runtime::Tokio1CurrentThread, || {
+        Ok::<_, ()>(Computer { answer: 42 })
+    })
+    .await
+    .unwrap();
+    assert_eq!(obj.interact(|computer| computer.answer).await.unwrap(), 42);
+}
+
+#[tokio::test]
+async fn interact_timeout() {
+    let obj = SyncWrapper::new(Runtime::Tokio1, || Ok::<_, ()>(Computer { answer: 42 }))
+        .await
+        .unwrap();
+    assert!(matches!(
+        obj.interact_timeout(Duration::from_millis(10), |_| {
+            thread::sleep(Duration::from_millis(100))
+        })
+        .await,
+        Err(InteractError::Timeout)
+    ));
+    assert_eq!(
+        obj.interact_timeout(Duration::from_secs(1), |computer| computer.answer)
+            .await
+            .unwrap(),
+        42
+    );
+}
+
+#[tokio::test]
+async fn interact_ref() {
+    let obj = SyncWrapper::new(Runtime::Tokio1, || Ok::<_, ()>(Computer { answer: 42 }))
+        .await
+        .unwrap();
+    assert_eq!(
+        obj.interact_ref(|computer| computer.answer).await.unwrap(),
+        42
+    );
+}
+
+#[tokio::test]
+async fn interact_backend() {
+    let obj = SyncWrapper::new(Runtime::Tokio1, || Ok::<_, ()>(Computer { answer: 42 }))
+        .await
+        .unwrap();
+    assert_eq!(
+        obj.interact_backend(|computer| Ok::<_, &str>(computer.answer))
+            .await
+            .unwrap(),
+        42
+    );
+    assert!(matches!(
+        obj.interact_backend(|_| Err::<usize, _>("failed")).await,
+        Err(InteractError::Backend("failed"))
+    ));
+}
+
+#[tokio::test]
+async fn runtime() {
+    let obj = SyncWrapper::new(Runtime::Tokio1, || Ok::<_, ()>(Computer { answer: 42 }))
+        .await
+        .unwrap();
+    assert_eq!(obj.runtime(), Runtime::Tokio1);
+}
+
+#[tokio::test]
+async fn debug() {
+    // `Computer` doesn't implement `Debug`.
+    let obj = SyncWrapper::new(Runtime::Tokio1, || Ok::<_, ()>(Computer { answer: 42 }))
+        .await
+        .unwrap();
+    assert_eq!(format!("{:?}", obj), "SyncWrapper { runtime: Tokio1, .. }");
+}
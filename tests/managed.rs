@@ -1,10 +1,16 @@
 #![cfg(feature = "managed")]
 
-use std::{convert::Infallible, time::Duration};
+use std::{
+    convert::Infallible,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
-use tokio::time;
+use tokio::{sync::Semaphore, time};
 
-use deadpool::managed::{self, Metrics, Object, PoolError, RecycleResult, Timeouts};
+use deadpool::managed::{
+    self, Metrics, Object, PoolError, QueueMode, RecycleResult, TimeoutType, Timeouts,
+};
 
 type Pool = managed::Pool<Manager>;
 
@@ -108,6 +114,29 @@ async fn closing() {
     assert_eq!(pool.status().waiting, 0);
 }
 
+#[tokio::test]
+async fn close_and_wait() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(2).build().unwrap();
+    drop(pool.get().await.unwrap());
+    let obj = pool.get().await.unwrap();
+    let holder = tokio::spawn(async move {
+        time::sleep(Duration::from_millis(20)).await;
+        drop(obj);
+    });
+    let start = Instant::now();
+    time::timeout(Duration::from_millis(1000), pool.close_and_wait())
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(20));
+    holder.await.unwrap();
+    assert!(pool.is_closed());
+    let status = pool.status();
+    assert_eq!(status.size, 0);
+    assert_eq!(status.available, 0);
+    assert_eq!(status.waiting, 0);
+}
+
 #[tokio::test(flavor = "multi_thread")]
 async fn concurrent() {
     let mgr = Manager {};
@@ -182,6 +211,208 @@ async fn object_take() {
     assert_eq!(status.available, 2);
     assert_eq!(status.waiting, 0);
 }
+#[tokio::test]
+async fn object_into_parts() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let (value, handle) = Object::into_parts(pool.get().await.unwrap());
+    assert_eq!(value, 0);
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.available, 0);
+    assert!(pool.try_get().await.is_err());
+
+    drop(Object::from_parts(42, handle));
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.available, 1);
+
+    let (value, handle) = Object::into_parts(pool.get().await.unwrap());
+    assert_eq!(value, 42);
+    assert_eq!(pool.object_counts().detached, 0);
+    drop(handle);
+    let status = pool.status();
+    assert_eq!(status.size, 0);
+    assert_eq!(status.available, 0);
+    assert_eq!(pool.object_counts().detached, 1);
+    assert_eq!(*pool.get().await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn clear() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(4).build().unwrap();
+    let mut borrowed = pool.get().await.unwrap();
+    *borrowed = 42;
+    {
+        let _a = pool.get().await.unwrap();
+        let _b = pool.get().await.unwrap();
+    }
+    assert_eq!(pool.status().size, 3);
+    pool.clear();
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.available, 0);
+    assert_eq!(status.max_size, 4);
+    assert_eq!(*borrowed, 42);
+    drop(borrowed);
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.available, 1);
+    let _objs = [
+        pool.get().await.unwrap(),
+        pool.get().await.unwrap(),
+        pool.get().await.unwrap(),
+        pool.get().await.unwrap(),
+    ];
+    assert_eq!(pool.status().size, 4);
+}
+
+#[tokio::test]
+async fn invalidate() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let mut obj = pool.get().await.unwrap();
+    *obj = 42;
+    Object::invalidate(&mut obj);
+    drop(obj);
+    let status = pool.status();
+    assert_eq!(status.size, 0);
+    assert_eq!(status.available, 0);
+    assert_eq!(status.max_size, 1);
+    let obj = pool.get().await.unwrap();
+    assert_eq!(*obj, 0);
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn inspect() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(3).build().unwrap();
+    let borrowed = pool.get().await.unwrap();
+    {
+        let mut a = pool.get().await.unwrap();
+        let mut b = pool.get().await.unwrap();
+        *a = 1;
+        *b = 2;
+    }
+    let mut seen = Vec::new();
+    pool.inspect(|obj, metrics| seen.push((*obj, metrics.recycle_count)));
+    seen.sort_unstable();
+    assert_eq!(seen, vec![(1, 0), (2, 0)]);
+    let status = pool.status();
+    assert_eq!(status.size, 3);
+    assert_eq!(status.available, 2);
+    drop(borrowed);
+}
+
+#[tokio::test]
+async fn size_accessors() {
+    let pool = Pool::builder(Manager {}).max_size(2).build().unwrap();
+    assert!(pool.is_empty());
+    assert_eq!(pool.current_size(), 0);
+    assert_eq!(pool.max_size(), 2);
+
+    let obj0 = pool.get().await.unwrap();
+    let mut obj1 = pool.get().await.unwrap();
+    assert!(!pool.is_empty());
+    assert_eq!(pool.current_size(), 2);
+
+    Object::invalidate(&mut obj1);
+    drop(obj1);
+    assert_eq!(pool.current_size(), 1);
+
+    pool.resize(4);
+    assert_eq!(pool.max_size(), 4);
+    assert_eq!(pool.current_size(), pool.status().size);
+    drop(obj0);
+
+    pool.close();
+    assert!(pool.is_empty());
+    assert_eq!(pool.max_size(), 0);
+}
+
+#[tokio::test]
+async fn status_detailed() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(3).build().unwrap();
+    let detailed = pool.status_detailed();
+    assert_eq!(detailed.idle, 0);
+    assert_eq!(detailed.max_age, Duration::ZERO);
+    let old = pool.get().await.unwrap();
+    time::sleep(Duration::from_millis(20)).await;
+    let young = pool.get().await.unwrap();
+    let borrowed = pool.get().await.unwrap();
+    drop(old);
+    drop(young);
+    let detailed = pool.status_detailed();
+    assert_eq!(detailed.status.size, 3);
+    assert_eq!(detailed.status.available, 2);
+    assert_eq!(detailed.idle, 2);
+    assert!(detailed.max_age >= Duration::from_millis(20));
+    assert!(detailed.min_age < Duration::from_millis(20));
+    assert!(detailed.min_age <= detailed.avg_age && detailed.avg_age <= detailed.max_age);
+    assert_eq!(detailed.max_recycle_count, 0);
+    // Recycle one of the idle objects
+    drop(pool.get().await.unwrap());
+    let detailed = pool.status_detailed();
+    assert_eq!(detailed.min_recycle_count, 0);
+    assert_eq!(detailed.max_recycle_count, 1);
+    assert_eq!(detailed.avg_recycle_count, 0.5);
+    assert_eq!(detailed.min_usage_count, 1);
+    assert_eq!(detailed.max_usage_count, 2);
+    assert_eq!(detailed.avg_usage_count, 1.5);
+    drop(borrowed);
+}
+
+#[tokio::test]
+async fn metrics_usage_count() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let obj = pool.get().await.unwrap();
+    assert_eq!(Object::metrics(&obj).usage_count, 1);
+    drop(obj);
+    let obj = pool.get().await.unwrap();
+    assert_eq!(Object::metrics(&obj).usage_count, 2);
+    assert_eq!(Object::metrics(&obj).recycle_count, 1);
+}
+
+#[tokio::test]
+async fn queue_mode_lru_by_metrics() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr)
+        .max_size(3)
+        .queue_mode(QueueMode::LruByMetrics)
+        .build()
+        .unwrap();
+    let mut objs = Vec::new();
+    for i in 1..=3 {
+        let mut obj = pool.get().await.unwrap();
+        *obj = i;
+        objs.push(obj);
+        time::sleep(Duration::from_millis(2)).await;
+    }
+    // Return the objects starting with the most recently created one
+    while let Some(obj) = objs.pop() {
+        drop(obj);
+    }
+    // The most recently created object is reused even though it was
+    // returned first.
+    for _ in 0..3 {
+        let obj = pool.get().await.unwrap();
+        assert_eq!(*obj, 3);
+        time::sleep(Duration::from_millis(2)).await;
+    }
+    // Objects which were used more recently are preferred
+    let obj = pool.get().await.unwrap();
+    time::sleep(Duration::from_millis(2)).await;
+    let obj2 = pool.get().await.unwrap();
+    assert_eq!((*obj, *obj2), (3, 2));
+    drop(obj);
+    drop(obj2);
+    assert_eq!(*pool.get().await.unwrap(), 2);
+}
+
 #[tokio::test]
 async fn retain() {
     let mgr = Manager {};
@@ -226,3 +457,195 @@ async fn retain_fnmut() {
     }
     assert_eq!(pool.status().size, 0);
 }
+
+#[tokio::test]
+async fn try_get() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+
+    let obj = pool.try_get().await.unwrap();
+    assert_eq!(pool.status().size, 1);
+    assert!(matches!(
+        pool.try_get().await,
+        Err(PoolError::Timeout(TimeoutType::Wait))
+    ));
+
+    drop(obj);
+    assert!(pool.try_get().await.is_ok());
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn timeout_counts() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let _obj = pool.get().await.unwrap();
+    assert!(pool.try_get().await.is_err());
+    assert!(pool.try_get().await.is_err());
+    let counts = pool.timeout_counts();
+    assert_eq!(counts.wait, 2);
+    assert_eq!(counts.create, 0);
+    assert_eq!(counts.recycle, 0);
+}
+
+#[tokio::test]
+async fn object_counts() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(2).build().unwrap();
+    for _ in 0..10 {
+        let _a = pool.get().await.unwrap();
+        let _b = pool.get().await.unwrap();
+    }
+    let counts = pool.object_counts();
+    assert_eq!(counts.created, 2);
+    assert_eq!(counts.detached, 0);
+
+    let _ = Object::take(pool.get().await.unwrap());
+    let mut obj = pool.get().await.unwrap();
+    Object::invalidate(&mut obj);
+    drop(obj);
+    let counts = pool.object_counts();
+    assert_eq!(counts.created, 2);
+    assert_eq!(counts.detached, 2);
+    drop(pool.get().await.unwrap());
+    assert_eq!(pool.object_counts().created, 3);
+}
+
+#[tokio::test]
+async fn metrics_age() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let obj = pool.get().await.unwrap();
+    let created = Object::metrics(&obj).created;
+    time::sleep(Duration::from_millis(5)).await;
+    assert!(Object::metrics(&obj).age() >= Duration::from_millis(5));
+    drop(obj);
+    let obj = pool.get().await.unwrap();
+    assert_eq!(Object::metrics(&obj).created, created);
+}
+
+#[tokio::test]
+async fn extensions() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let mut obj = pool.get().await.unwrap();
+    assert!(Object::extensions(&obj).is_empty());
+    assert_eq!(Object::extensions_mut(&mut obj).insert(42u32), None);
+    assert_eq!(Object::extensions_mut(&mut obj).insert(43u32), Some(42));
+    *Object::extensions_mut(&mut obj).get_mut::<u32>().unwrap() += 1;
+    drop(obj);
+    let mut obj = pool.get().await.unwrap();
+    assert_eq!(Object::extensions(&obj).get::<u32>(), Some(&44));
+    assert_eq!(Object::extensions(&obj).get::<u64>(), None);
+    assert_eq!(Object::extensions_mut(&mut obj).remove::<u32>(), Some(44));
+    assert!(Object::extensions(&obj).is_empty());
+}
+
+#[tokio::test]
+async fn retain_async() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(4).build().unwrap();
+    {
+        let mut objs = Vec::new();
+        for i in 0..4 {
+            let mut obj = pool.get().await.unwrap();
+            *obj = i;
+            objs.push(obj);
+        }
+    }
+    assert_eq!(pool.status().size, 4);
+    let retain_result = pool
+        .retain_async(|obj, _| {
+            Box::pin(async move {
+                tokio::task::yield_now().await;
+                *obj % 2 == 0
+            })
+        })
+        .await;
+    assert_eq!(retain_result.retained, 2);
+    assert_eq!(retain_result.removed, vec![1, 3]);
+    let status = pool.status();
+    assert_eq!(status.size, 2);
+    assert_eq!(status.available, 2);
+}
+
+#[tokio::test]
+async fn wait_stats() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    assert_eq!(pool.wait_stats().count, 0);
+    assert_eq!(pool.wait_stats().average(), Duration::ZERO);
+
+    let obj = pool.get().await.unwrap();
+    let join_handle = {
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            let _ = pool.get().await.unwrap();
+        })
+    };
+    time::sleep(Duration::from_millis(10)).await;
+    drop(obj);
+    join_handle.await.unwrap();
+
+    let stats = pool.wait_stats();
+    assert_eq!(stats.count, 2);
+    assert!(stats.last >= Duration::from_millis(10));
+    assert!(stats.total >= stats.last);
+    assert!(stats.average() >= Duration::from_millis(5));
+}
+
+#[tokio::test]
+async fn get_timed() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(1).build().unwrap();
+    let (obj, _) = pool.get_timed().await.unwrap();
+    let waiter = {
+        let pool = pool.clone();
+        tokio::spawn(async move { pool.get_timed().await.unwrap().1 })
+    };
+    time::sleep(Duration::from_millis(50)).await;
+    drop(obj);
+    assert!(waiter.await.unwrap() >= Duration::from_millis(50));
+}
+
+#[tokio::test]
+async fn shared_limit() {
+    let limit = Arc::new(Semaphore::new(1));
+    let pool_a = Pool::builder(Manager {})
+        .max_size(2)
+        .shared_limit(limit.clone())
+        .build()
+        .unwrap();
+    let pool_b = Pool::builder(Manager {})
+        .max_size(2)
+        .shared_limit(limit.clone())
+        .build()
+        .unwrap();
+
+    let obj = pool_a.get().await.unwrap();
+    assert_eq!(limit.available_permits(), 0);
+    assert!(matches!(
+        pool_b.try_get().await,
+        Err(PoolError::Timeout(TimeoutType::Wait))
+    ));
+    assert_eq!(pool_b.status().size, 0);
+
+    let waiter = tokio::spawn(async move { pool_b.get().await.map(|_| ()) });
+    time::sleep(Duration::from_millis(10)).await;
+    assert!(!waiter.is_finished());
+    drop(obj);
+    waiter.await.unwrap().unwrap();
+    assert_eq!(limit.available_permits(), 1);
+}
+
+#[tokio::test]
+async fn recycle_now() {
+    let pool = Pool::builder(Manager {}).max_size(1).build().unwrap();
+    let mut obj = pool.get().await.unwrap();
+    assert_eq!(Object::metrics(&obj).recycle_count, 0);
+    Object::recycle_now(&mut obj).await.unwrap();
+    assert_eq!(Object::metrics(&obj).recycle_count, 1);
+    assert!(Object::metrics(&obj).recycled.is_some());
+    drop(obj);
+    assert_eq!(pool.status().available, 1);
+}
@@ -5,7 +5,7 @@ use std::{collections::HashMap, env, time::Duration};
 use config::Config;
 use serde::{Deserialize, Serialize};
 
-use deadpool::managed::PoolConfig;
+use deadpool::managed::{PoolConfig, QueueMode};
 
 struct Env {
     backup: HashMap<String, Option<String>>,
@@ -62,3 +62,70 @@ fn from_env() {
     assert_eq!(cfg.pool.timeouts.create, Some(Duration::from_secs(2)));
     assert_eq!(cfg.pool.timeouts.recycle, Some(Duration::from_secs(3)));
 }
+
+#[test]
+fn from_env_seconds() {
+    let mut env = Env::new();
+    env.set("SECONDS__POOL__MAX_SIZE", "42");
+    env.set("SECONDS__POOL__QUEUE_MODE", "lifo");
+    env.set("SECONDS__POOL__TIMEOUTS__WAIT", "5");
+    env.set("SECONDS__POOL__TIMEOUTS__CREATE", "0.5");
+
+    let cfg = Config::builder()
+        .add_source(
+            config::Environment::with_prefix("SECONDS")
+                .prefix_separator("__")
+                .separator("__"),
+        )
+        .build()
+        .unwrap()
+        .try_deserialize::<TestConfig>()
+        .unwrap();
+
+    assert!(matches!(cfg.pool.queue_mode, QueueMode::Lifo));
+    assert_eq!(cfg.pool.timeouts.wait, Some(Duration::from_secs(5)));
+    assert_eq!(cfg.pool.timeouts.create, Some(Duration::from_millis(500)));
+    assert_eq!(cfg.pool.timeouts.recycle, None);
+}
+
+fn from_json(json: &str) -> Result<TestConfig, config::ConfigError> {
+    Config::builder()
+        .add_source(config::File::from_str(json, config::FileFormat::Json))
+        .build()
+        .unwrap()
+        .try_deserialize::<TestConfig>()
+}
+
+#[test]
+fn from_json_timeouts() {
+    let cfg = from_json(
+        r#"{"pool": {"max_size": 1, "timeouts": {
+            "wait": 2,
+            "create": 1.5,
+            "recycle": {"secs": 3, "nanos": 0}
+        }}}"#,
+    )
+    .unwrap();
+    assert_eq!(cfg.pool.timeouts.wait, Some(Duration::from_secs(2)));
+    assert_eq!(cfg.pool.timeouts.create, Some(Duration::from_millis(1500)));
+    assert_eq!(cfg.pool.timeouts.recycle, Some(Duration::from_secs(3)));
+
+    assert!(from_json(r#"{"pool": {"max_size": 1, "timeouts": {"wait": -1}}}"#).is_err());
+}
+
+#[test]
+fn from_json_queue_mode() {
+    for (name, mode) in [
+        ("Fifo", QueueMode::Fifo),
+        ("LIFO", QueueMode::Lifo),
+        ("lrubymetrics", QueueMode::LruByMetrics),
+    ] {
+        let json = format!(r#"{{"pool": {{"max_size": 1, "queue_mode": "{}"}}}}"#, name);
+        let cfg = from_json(&json).unwrap();
+        assert_eq!(
+            std::mem::discriminant(&cfg.pool.queue_mode),
+            std::mem::discriminant(&mode)
+        );
+    }
+    assert!(from_json(r#"{"pool": {"max_size": 1, "queue_mode": "random"}}"#).is_err());
+}
@@ -0,0 +1,104 @@
+#![cfg(all(feature = "managed", feature = "rt_tokio_1"))]
+
+use std::{
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use deadpool::{
+    managed::{self, BuildError, Metrics, PoolError, RecycleResult, RetryConfig, TimeoutType},
+    Runtime,
+};
+
+type Pool = managed::Pool<Manager>;
+
+struct Manager {
+    failures: usize,
+    attempts: AtomicUsize,
+}
+
+impl Manager {
+    fn new(failures: usize) -> Self {
+        Self {
+            failures,
+            attempts: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = ();
+    type Error = ();
+
+    async fn create(&self) -> Result<(), ()> {
+        if self.attempts.fetch_add(1, Ordering::Relaxed) < self.failures {
+            Err(())
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<()> {
+        Ok(())
+    }
+}
+
+const RETRY: RetryConfig = RetryConfig {
+    max_retries: 3,
+    base_delay: Duration::from_millis(1),
+    max_delay: Duration::from_millis(4),
+};
+
+#[tokio::test]
+async fn retry_succeeds() {
+    let pool = Pool::builder(Manager::new(3))
+        .max_size(1)
+        .create_retry(RETRY)
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    assert!(pool.get().await.is_ok());
+    assert_eq!(pool.manager().attempts.load(Ordering::Relaxed), 4);
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn retry_exhausted() {
+    let pool = Pool::builder(Manager::new(4))
+        .max_size(1)
+        .create_retry(RETRY)
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    assert!(matches!(pool.get().await, Err(PoolError::Backend(()))));
+    assert_eq!(pool.manager().attempts.load(Ordering::Relaxed), 4);
+    assert_eq!(pool.status().size, 0);
+}
+
+#[tokio::test]
+async fn retry_create_timeout() {
+    let pool = Pool::builder(Manager::new(usize::MAX))
+        .max_size(1)
+        .create_retry(RetryConfig {
+            max_retries: usize::MAX,
+            base_delay: Duration::from_millis(5),
+            max_delay: Duration::from_millis(5),
+        })
+        .create_timeout(Some(Duration::from_millis(20)))
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::Timeout(TimeoutType::Create))
+    ));
+    assert_eq!(pool.status().size, 0);
+}
+
+#[test]
+fn retry_requires_runtime() {
+    assert!(matches!(
+        Pool::builder(Manager::new(0)).create_retry(RETRY).build(),
+        Err(BuildError::NoRuntimeSpecified)
+    ));
+}
@@ -0,0 +1,93 @@
+#![cfg(all(feature = "managed", feature = "rt_tokio_1"))]
+
+use std::{
+    convert::Infallible,
+    time::{Duration, Instant},
+};
+
+use deadpool::{
+    managed::{self, Metrics, PoolError, RecycleResult, TimeoutType},
+    Runtime,
+};
+
+type Pool = managed::Pool<Manager>;
+
+struct Manager {
+    create_delay: Duration,
+}
+
+impl managed::Manager for Manager {
+    type Type = ();
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<(), Infallible> {
+        tokio::time::sleep(self.create_delay).await;
+        Ok(())
+    }
+
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+fn pool(create_delay: Duration) -> Pool {
+    Pool::builder(Manager { create_delay })
+        .max_size(1)
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn deadline_ok() {
+    let pool = pool(Duration::ZERO);
+    let deadline = Instant::now() + Duration::from_secs(1);
+    assert!(pool.get_deadline(deadline).await.is_ok());
+}
+
+#[tokio::test]
+async fn deadline_passed() {
+    let pool = pool(Duration::ZERO);
+    assert!(matches!(
+        pool.get_deadline(Instant::now()).await,
+        Err(PoolError::Timeout(TimeoutType::Wait))
+    ));
+    // Nothing must have been created
+    assert_eq!(pool.status().size, 0);
+}
+
+#[tokio::test]
+async fn deadline_wait() {
+    let pool = pool(Duration::ZERO);
+    let _obj = pool.get().await.unwrap();
+    let deadline = Instant::now() + Duration::from_millis(10);
+    assert!(matches!(
+        pool.get_deadline(deadline).await,
+        Err(PoolError::Timeout(TimeoutType::Wait))
+    ));
+}
+
+#[tokio::test]
+async fn deadline_create() {
+    let pool = pool(Duration::from_secs(1));
+    let deadline = Instant::now() + Duration::from_millis(10);
+    assert!(matches!(
+        pool.get_deadline(deadline).await,
+        Err(PoolError::Timeout(TimeoutType::Create))
+    ));
+}
+
+#[tokio::test]
+async fn deadline_no_runtime() {
+    let pool = Pool::builder(Manager {
+        create_delay: Duration::ZERO,
+    })
+    .max_size(1)
+    .build()
+    .unwrap();
+    let deadline = Instant::now() + Duration::from_secs(1);
+    assert!(matches!(
+        pool.get_deadline(deadline).await,
+        Err(PoolError::NoRuntimeSpecified)
+    ));
+}
@@ -2,16 +2,21 @@
 
 use std::sync::atomic::{AtomicUsize, Ordering};
 
-use deadpool::managed::{Hook, HookError, Manager, Metrics, Pool, RecycleResult};
+use deadpool::managed::{
+    GetOptions, Hook, HookError, Manager, Metrics, Object, Pool, PoolError, PreCreateHook,
+    RecycleResult,
+};
 
 struct Computer {
     next_id: AtomicUsize,
+    detached: AtomicUsize,
 }
 
 impl Computer {
     pub fn new(start: usize) -> Self {
         Self {
             next_id: AtomicUsize::new(start),
+            detached: AtomicUsize::new(0),
         }
     }
 }
@@ -27,6 +32,65 @@ impl Manager for Computer {
     async fn recycle(&self, _: &mut Self::Type, _: &Metrics) -> RecycleResult<Self::Error> {
         Ok(())
     }
+
+    fn detach(&self, _: &mut Self::Type) {
+        let _ = self.detached.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[tokio::test]
+async fn pre_create_ok() {
+    let manager = Computer::new(42);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .pre_create(PreCreateHook::sync_fn(|manager: &Computer| {
+            manager.next_id.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }))
+        .build()
+        .unwrap();
+    assert!(*pool.get().await.unwrap() == 43);
+}
+
+#[tokio::test]
+async fn pre_create_ok_async() {
+    let manager = Computer::new(42);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .pre_create(PreCreateHook::async_fn(|manager: &Computer| {
+            Box::pin(async move {
+                manager.next_id.fetch_add(1, Ordering::Relaxed);
+                Ok(())
+            })
+        }))
+        .build()
+        .unwrap();
+    assert!(*pool.get().await.unwrap() == 43);
+}
+
+#[tokio::test]
+async fn pre_create_err_abort() {
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(3)
+        .pre_create(PreCreateHook::sync_fn(|manager: &Computer| {
+            (manager.next_id.load(Ordering::Relaxed) < 2)
+                .then_some(())
+                .ok_or(HookError::message("too many objects"))
+        }))
+        .build()
+        .unwrap();
+    let obj1 = pool.get().await.unwrap();
+    assert_eq!(*obj1, 0);
+    let obj2 = pool.get().await.unwrap();
+    assert_eq!(*obj2, 1);
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::PreCreateHook(HookError::Message(_)))
+    ));
+    let status = pool.status();
+    assert_eq!(status.size, 2);
+    assert_eq!(status.waiting, 0);
 }
 
 #[tokio::test]
@@ -81,6 +145,128 @@ async fn post_create_err_abort() {
     assert_eq!(*obj2, 4);
 }
 
+#[tokio::test]
+async fn post_create_err_retry() {
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_create(Hook::sync_fn(|obj, _| {
+            (*obj > 0)
+                .then_some(())
+                .ok_or(HookError::retry("handshake failed"))
+        }))
+        .build()
+        .unwrap();
+    assert_eq!(*pool.get().await.unwrap(), 1);
+    let status = pool.status();
+    assert_eq!(status.size, 1);
+    assert_eq!(status.available, 1);
+}
+
+#[tokio::test]
+async fn post_create_err_retry_limit() {
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_create(Hook::sync_fn(|_, _| {
+            Err(HookError::retry("handshake failed"))
+        }))
+        .build()
+        .unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::PostCreateHook(HookError::Retry(_)))
+    ));
+    assert_eq!(pool.status().size, 0);
+    assert_eq!(pool.manager().next_id.load(Ordering::Relaxed), 17);
+}
+
+#[cfg(feature = "rt_tokio_1")]
+#[tokio::test]
+async fn post_create_err_retry_timeout() {
+    use std::time::Duration;
+
+    use deadpool::{managed::TimeoutType, Runtime};
+
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .create_timeout(Some(Duration::from_millis(10)))
+        .runtime(Runtime::Tokio1)
+        .post_create(Hook::async_fn(|_, _| {
+            Box::pin(async move {
+                tokio::time::sleep(Duration::from_millis(2)).await;
+                Err(HookError::retry("handshake failed"))
+            })
+        }))
+        .build()
+        .unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::Timeout(TimeoutType::Create))
+    ));
+    assert_eq!(pool.status().size, 0);
+}
+
+#[tokio::test]
+async fn post_create_replace() {
+    let manager = Computer::new(42);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_create(Hook::sync_replace_fn(|obj, _| Ok(Some(*obj * 2))))
+        .build()
+        .unwrap();
+    assert_eq!(*pool.get().await.unwrap(), 84);
+    assert_eq!(*pool.get().await.unwrap(), 84);
+    assert_eq!(pool.manager().detached.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn post_create_replace_async() {
+    let manager = Computer::new(42);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_create(Hook::async_replace_fn(|obj, _| {
+            Box::pin(async move { Ok(Some(*obj * 2)) })
+        }))
+        .build()
+        .unwrap();
+    assert_eq!(*pool.get().await.unwrap(), 84);
+}
+
+#[tokio::test]
+async fn post_create_replace_keep() {
+    let manager = Computer::new(42);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_create(Hook::sync_replace_fn(|_, _| Ok(None)))
+        .post_create(Hook::sync_fn(|obj, _| {
+            *obj += 1;
+            Ok(())
+        }))
+        .build()
+        .unwrap();
+    assert_eq!(*pool.get().await.unwrap(), 43);
+    assert_eq!(pool.manager().detached.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn post_recycle_replace() {
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_recycle(Hook::sync_replace_fn(|obj, metrics| {
+            Ok(Some(*obj + metrics.recycle_count + 10))
+        }))
+        .build()
+        .unwrap();
+    assert_eq!(*pool.get().await.unwrap(), 0);
+    assert_eq!(*pool.get().await.unwrap(), 10);
+    assert_eq!(*pool.get().await.unwrap(), 21);
+    assert_eq!(pool.status().size, 1);
+    assert_eq!(pool.manager().detached.load(Ordering::Relaxed), 2);
+}
+
 #[tokio::test]
 async fn pre_recycle_ok() {
     let manager = Computer::new(42);
@@ -182,3 +368,54 @@ async fn post_recycle_err_continue() {
     assert_eq!(pool.status().available, 1);
     assert_eq!(pool.status().size, 1);
 }
+
+#[tokio::test]
+async fn recycle_skip_hooks() {
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .pre_recycle(Hook::sync_fn(|obj, _| {
+            *obj += 1;
+            Ok(())
+        }))
+        .post_recycle(Hook::sync_fn(|obj, _| {
+            *obj += 10;
+            Ok(())
+        }))
+        .build()
+        .unwrap();
+    let skip_hooks = GetOptions {
+        skip_hooks: true,
+        ..Default::default()
+    };
+    assert_eq!(*pool.get_with(skip_hooks).await.unwrap(), 0);
+    let obj = pool.get_with(skip_hooks).await.unwrap();
+    assert_eq!(*obj, 0);
+    assert_eq!(Object::metrics(&obj).recycle_count, 1);
+    drop(obj);
+    assert_eq!(*pool.get().await.unwrap(), 11);
+}
+
+#[tokio::test]
+async fn recycle_skip_recycle() {
+    let manager = Computer::new(0);
+    let pool = Pool::<Computer>::builder(manager)
+        .max_size(1)
+        .post_recycle(Hook::sync_fn(|obj, _| {
+            *obj += 1;
+            Ok(())
+        }))
+        .build()
+        .unwrap();
+    drop(pool.get().await.unwrap());
+    let obj = pool
+        .get_with(GetOptions {
+            skip_recycle: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    // Hooks still run but the object doesn't count as recycled.
+    assert_eq!(*obj, 1);
+    assert_eq!(Object::metrics(&obj).recycle_count, 0);
+}
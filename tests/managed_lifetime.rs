@@ -0,0 +1,130 @@
+#![cfg(feature = "managed")]
+
+use std::{
+    convert::Infallible,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use deadpool::managed::{self, Metrics, RecycleResult};
+
+type Pool = managed::Pool<Manager>;
+
+struct Manager {
+    created: AtomicUsize,
+    recycled: AtomicUsize,
+}
+
+impl Manager {
+    fn new() -> Self {
+        Self {
+            created: AtomicUsize::new(0),
+            recycled: AtomicUsize::new(0),
+        }
+    }
+}
+
+impl managed::Manager for Manager {
+    type Type = usize;
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<usize, Infallible> {
+        Ok(self.created.fetch_add(1, Ordering::Relaxed))
+    }
+
+    async fn recycle(&self, _conn: &mut usize, _: &Metrics) -> RecycleResult<Infallible> {
+        let _ = self.recycled.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn max_lifetime() {
+    let pool = Pool::builder(Manager::new())
+        .max_size(1)
+        .max_lifetime(Some(Duration::from_millis(10)))
+        .build()
+        .unwrap();
+
+    assert_eq!(*pool.get().await.unwrap(), 0);
+    assert_eq!(*pool.get().await.unwrap(), 0);
+    assert_eq!(pool.manager().recycled.load(Ordering::Relaxed), 1);
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(*pool.get().await.unwrap(), 1);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 2);
+    // The expired object must not be passed to `Manager::recycle`
+    assert_eq!(pool.manager().recycled.load(Ordering::Relaxed), 1);
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn max_lifetime_none() {
+    let pool = Pool::builder(Manager::new()).max_size(1).build().unwrap();
+
+    assert_eq!(*pool.get().await.unwrap(), 0);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(*pool.get().await.unwrap(), 0);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn idle_timeout() {
+    let pool = Pool::builder(Manager::new())
+        .max_size(1)
+        .idle_timeout(Some(Duration::from_millis(10)))
+        .build()
+        .unwrap();
+
+    assert_eq!(*pool.get().await.unwrap(), 0);
+    assert_eq!(*pool.get().await.unwrap(), 0);
+
+    tokio::time::sleep(Duration::from_millis(20)).await;
+
+    assert_eq!(*pool.get().await.unwrap(), 1);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 2);
+    assert_eq!(pool.manager().recycled.load(Ordering::Relaxed), 1);
+    assert_eq!(pool.status().size, 1);
+}
+
+#[cfg(feature = "rt_tokio_1")]
+#[tokio::test]
+async fn spawn_maintenance() {
+    use deadpool::{managed::MaintenanceConfig, Runtime};
+
+    let pool = Pool::builder(Manager::new())
+        .max_size(2)
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    let handle = pool
+        .spawn_maintenance(MaintenanceConfig {
+            max_idle: Some(Duration::from_millis(20)),
+            ..MaintenanceConfig::new(Duration::from_millis(10))
+        })
+        .unwrap();
+    drop((pool.get().await.unwrap(), pool.get().await.unwrap()));
+    assert_eq!(pool.status().size, 2);
+
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(pool.status().size, 0);
+    assert_eq!(*pool.get().await.unwrap(), 2);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 3);
+
+    // Dropping the handle stops the task.
+    drop(handle);
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(pool.status().size, 1);
+}
+
+#[tokio::test]
+async fn spawn_maintenance_without_runtime() {
+    use deadpool::managed::{MaintenanceConfig, PoolError};
+
+    let pool = Pool::builder(Manager::new()).build().unwrap();
+    assert!(matches!(
+        pool.spawn_maintenance(MaintenanceConfig::new(Duration::from_secs(1))),
+        Err(PoolError::NoRuntimeSpecified)
+    ));
+}
@@ -0,0 +1,129 @@
+#![cfg(all(feature = "managed", feature = "metrics"))]
+
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, SharedString, Unit};
+
+use deadpool::managed::{self, Metrics, Object, PoolError, RecycleResult};
+
+struct Manager {}
+
+impl managed::Manager for Manager {
+    type Type = ();
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+/// Recorder storing the values of all metrics in atomics.
+#[derive(Default)]
+struct Recorder {
+    values: Mutex<HashMap<String, Arc<AtomicU64>>>,
+}
+
+impl Recorder {
+    fn value(&self, key: &str) -> Arc<AtomicU64> {
+        self.values
+            .lock()
+            .unwrap()
+            .entry(key.to_owned())
+            .or_default()
+            .clone()
+    }
+
+    fn key(key: &Key) -> String {
+        key.labels().fold(key.name().to_owned(), |name, label| {
+            format!("{}{{{}={}}}", name, label.key(), label.value())
+        })
+    }
+
+    fn counter(&self, key: &str) -> u64 {
+        self.value(key).load(Ordering::Relaxed)
+    }
+
+    fn gauge(&self, key: &str) -> f64 {
+        f64::from_bits(self.value(key).load(Ordering::Relaxed))
+    }
+}
+
+impl metrics::Recorder for Recorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        Counter::from_arc(self.value(&Self::key(key)))
+    }
+    fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::from_arc(self.value(&Self::key(key)))
+    }
+    fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+        Histogram::noop()
+    }
+}
+
+#[tokio::test]
+async fn register_metrics() {
+    let recorder = Recorder::default();
+    let pool = managed::Pool::<Manager>::builder(Manager {})
+        .max_size(2)
+        .build()
+        .unwrap();
+    metrics::with_local_recorder(&recorder, || pool.register_metrics("test"));
+    assert_eq!(recorder.gauge("test_pool_max_size"), 2.0);
+    assert_eq!(recorder.gauge("test_pool_size"), 0.0);
+
+    let obj0 = pool.get().await.unwrap();
+    let mut obj1 = pool.get().await.unwrap();
+    assert_eq!(recorder.gauge("test_pool_size"), 2.0);
+    assert_eq!(recorder.gauge("test_pool_available"), 0.0);
+    assert_eq!(recorder.counter("test_pool_objects_created_total"), 2);
+    assert!(matches!(pool.try_get().await, Err(PoolError::Timeout(_))));
+    assert_eq!(recorder.counter("test_pool_timeouts_total{type=wait}"), 1);
+
+    drop(obj0);
+    Object::invalidate(&mut obj1);
+    drop(obj1);
+    assert_eq!(recorder.gauge("test_pool_size"), 1.0);
+    assert_eq!(recorder.gauge("test_pool_available"), 1.0);
+    assert_eq!(recorder.counter("test_pool_objects_detached_total"), 1);
+
+    drop(pool.get().await.unwrap());
+    assert_eq!(recorder.counter("test_pool_objects_recycled_total"), 1);
+    assert_eq!(recorder.gauge("test_pool_waiting"), 0.0);
+}
+
+#[tokio::test]
+async fn retain_updates_metrics() {
+    let recorder = Recorder::default();
+    let pool = managed::Pool::<Manager>::builder(Manager {})
+        .max_size(2)
+        .build()
+        .unwrap();
+    metrics::with_local_recorder(&recorder, || pool.register_metrics("test"));
+    drop((pool.get().await.unwrap(), pool.get().await.unwrap()));
+    assert_eq!(recorder.gauge("test_pool_size"), 2.0);
+
+    let _ = pool.retain(|_, _| false);
+    assert_eq!(recorder.gauge("test_pool_size"), 0.0);
+
+    drop((pool.get().await.unwrap(), pool.get().await.unwrap()));
+    assert_eq!(recorder.gauge("test_pool_size"), 2.0);
+
+    let _ = pool.retain_async(|_, _| Box::pin(async { false })).await;
+    assert_eq!(recorder.gauge("test_pool_size"), 0.0);
+    assert_eq!(recorder.gauge("test_pool_available"), 0.0);
+    assert_eq!(recorder.counter("test_pool_objects_detached_total"), 4);
+}
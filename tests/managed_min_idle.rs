@@ -0,0 +1,103 @@
+#![cfg(all(feature = "managed", feature = "rt_tokio_1"))]
+
+use std::{
+    convert::Infallible,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+use deadpool::{
+    managed::{self, BuildError, Metrics, Object, RecycleResult},
+    Runtime,
+};
+
+type Pool = managed::Pool<Manager>;
+
+struct Manager {
+    created: AtomicUsize,
+}
+
+impl managed::Manager for Manager {
+    type Type = usize;
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<usize, Infallible> {
+        Ok(self.created.fetch_add(1, Ordering::Relaxed))
+    }
+
+    async fn recycle(&self, _conn: &mut usize, _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+fn manager() -> Manager {
+    Manager {
+        created: AtomicUsize::new(0),
+    }
+}
+
+#[test]
+fn min_idle_requires_runtime() {
+    let result = Pool::builder(manager()).min_idle(Some(2)).build();
+    assert!(matches!(result, Err(BuildError::NoRuntimeSpecified)));
+}
+
+#[tokio::test]
+async fn warmup() {
+    let pool = Pool::builder(manager())
+        .max_size(4)
+        .min_idle(Some(2))
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    assert_eq!(pool.status().size, 0);
+
+    pool.warmup().await.unwrap();
+    let status = pool.status();
+    assert_eq!(status.size, 2);
+    assert_eq!(status.available, 2);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn fill_without_warmup() {
+    let pool = Pool::builder(manager())
+        .max_size(4)
+        .min_idle(Some(2))
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(pool.status().size, 2);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 2);
+}
+
+#[tokio::test]
+async fn warmup_respects_max_size() {
+    let pool = Pool::builder(manager())
+        .max_size(2)
+        .min_idle(Some(4))
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    pool.warmup().await.unwrap();
+    assert_eq!(pool.status().size, 2);
+}
+
+#[tokio::test]
+async fn replenish() {
+    let pool = Pool::builder(manager())
+        .max_size(4)
+        .min_idle(Some(2))
+        .runtime(Runtime::Tokio1)
+        .build()
+        .unwrap();
+    pool.warmup().await.unwrap();
+
+    let _ = Object::take(pool.get().await.unwrap());
+    assert_eq!(pool.status().size, 1);
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    assert_eq!(pool.status().size, 2);
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 3);
+}
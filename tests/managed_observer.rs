@@ -0,0 +1,87 @@
+#![cfg(feature = "managed")]
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use deadpool::managed::{
+    self, Metrics, Object, PoolError, PoolObserver, RecycleResult, TimeoutType, Timeouts,
+};
+
+type Pool = managed::Pool<Manager>;
+
+struct Manager {}
+
+impl managed::Manager for Manager {
+    type Type = usize;
+    type Error = ();
+
+    async fn create(&self) -> Result<usize, ()> {
+        Ok(0)
+    }
+
+    async fn recycle(&self, _conn: &mut usize, _: &Metrics) -> RecycleResult<()> {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Observer {
+    created: AtomicUsize,
+    recycled: AtomicUsize,
+    detached: AtomicUsize,
+    timeouts: AtomicUsize,
+    acquired: AtomicUsize,
+}
+
+impl PoolObserver<Manager> for Observer {
+    fn on_create(&self, _obj: &usize) {
+        self.created.fetch_add(1, Ordering::Relaxed);
+    }
+    fn on_recycle(&self, _obj: &usize, _metrics: &Metrics) {
+        self.recycled.fetch_add(1, Ordering::Relaxed);
+    }
+    fn on_detach(&self, _obj: &usize) {
+        self.detached.fetch_add(1, Ordering::Relaxed);
+    }
+    fn on_timeout(&self, timeout_type: TimeoutType) {
+        assert!(matches!(timeout_type, TimeoutType::Wait));
+        self.timeouts.fetch_add(1, Ordering::Relaxed);
+    }
+    fn on_acquire(&self, _metrics: &Metrics) {
+        self.acquired.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[tokio::test]
+async fn observer() {
+    let observer = Arc::new(Observer::default());
+    let pool = Pool::builder(Manager {})
+        .max_size(1)
+        .observer(observer.clone())
+        .build()
+        .unwrap();
+
+    let obj = pool.get().await.unwrap();
+    assert!(matches!(
+        pool.timeout_get(&Timeouts {
+            wait: Some(Duration::ZERO),
+            ..pool.timeouts()
+        })
+        .await,
+        Err(PoolError::Timeout(TimeoutType::Wait))
+    ));
+    drop(obj);
+    let obj = pool.get().await.unwrap();
+    let _ = Object::take(obj);
+
+    assert_eq!(observer.created.load(Ordering::Relaxed), 1);
+    assert_eq!(observer.recycled.load(Ordering::Relaxed), 1);
+    assert_eq!(observer.detached.load(Ordering::Relaxed), 1);
+    assert_eq!(observer.timeouts.load(Ordering::Relaxed), 1);
+    assert_eq!(observer.acquired.load(Ordering::Relaxed), 2);
+}
@@ -2,7 +2,7 @@
 
 use std::convert::Infallible;
 
-use deadpool::managed::{self, Metrics, Object, RecycleResult};
+use deadpool::managed::{self, Metrics, Object, PoolError, RecycleResult, TimeoutType};
 
 type Pool = managed::Pool<Manager, Object<Manager>>;
 
@@ -102,6 +102,20 @@ async fn resize_pool_shrink_grow() {
     assert_eq!(pool.status().size, 1);
 }
 
+#[tokio::test]
+async fn resize_pool_zero_max_size() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(0).build().unwrap();
+    assert!(matches!(
+        pool.try_get().await,
+        Err(PoolError::Timeout(TimeoutType::Wait))
+    ));
+    assert_eq!(pool.status().waiting, 0);
+    pool.resize(1);
+    let _obj = pool.try_get().await.unwrap();
+    assert_eq!(pool.status().size, 1);
+}
+
 #[tokio::test]
 async fn resize_pool_grow_concurrent() {
     let mgr = Manager {};
@@ -146,3 +160,46 @@ async fn close_resize() {
     assert_eq!(pool.status().size, 0);
     assert_eq!(pool.status().max_size, 0);
 }
+
+#[tokio::test]
+async fn resize_with_evicted() {
+    let mgr = Manager {};
+    let pool = Pool::builder(mgr).max_size(3).build().unwrap();
+    {
+        let _objs = [
+            pool.get().await.unwrap(),
+            pool.get().await.unwrap(),
+            pool.get().await.unwrap(),
+        ];
+    }
+    let obj0 = pool.get().await.unwrap();
+    let mut evicted = 0;
+    pool.resize_with(1, |()| {
+        // The pool must not be locked while calling this function.
+        assert_eq!(pool.status().max_size, 1);
+        evicted += 1;
+    });
+    assert_eq!(evicted, 2);
+    assert_eq!(pool.status().size, 1);
+    drop(obj0);
+    assert_eq!(pool.status().size, 1);
+    assert_eq!(pool.status().available, 1);
+}
+
+#[tokio::test]
+async fn resize_and_fill() {
+    let pool = Pool::builder(Manager {}).max_size(2).build().unwrap();
+    let obj = pool.get().await.unwrap();
+    pool.resize_and_fill(4).await.unwrap();
+    let status = pool.status();
+    assert_eq!(status.size, 4);
+    assert_eq!(status.max_size, 4);
+    assert_eq!(status.available, 3);
+    drop(obj);
+
+    pool.close();
+    assert!(matches!(
+        pool.resize_and_fill(4).await,
+        Err(PoolError::Closed)
+    ));
+}
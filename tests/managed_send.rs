@@ -0,0 +1,75 @@
+#![cfg(feature = "managed")]
+
+use std::{cell::Cell, convert::Infallible};
+
+use deadpool::managed::{self, Metrics, Object, RecycleResult};
+
+/// A [`managed::Manager`] whose objects are [`Send`] but not [`Sync`].
+struct Manager {}
+
+impl managed::Manager for Manager {
+    type Type = Cell<usize>;
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<Cell<usize>, Infallible> {
+        Ok(Cell::new(0))
+    }
+
+    async fn recycle(&self, _: &mut Cell<usize>, _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+type Pool = managed::Pool<Manager>;
+
+/// Wrapper around [`Object`] just like the ones used by the backend crates.
+struct Wrapper {
+    obj: Object<Manager>,
+}
+
+impl From<Object<Manager>> for Wrapper {
+    fn from(obj: Object<Manager>) -> Self {
+        Self { obj }
+    }
+}
+
+type WrapperPool = managed::Pool<Manager, Wrapper>;
+
+fn assert_send<T: Send>(_: &T) {}
+
+fn assert_send_static<T: Send + 'static>() {}
+
+#[test]
+fn object_is_send_static() {
+    assert_send_static::<Object<Manager>>();
+    assert_send_static::<Wrapper>();
+    assert_send_static::<Pool>();
+    assert_send_static::<WrapperPool>();
+}
+
+#[test]
+fn get_is_send() {
+    let pool = Pool::builder(Manager {}).build().unwrap();
+    assert_send(&pool.get());
+    let pool = WrapperPool::builder(Manager {}).build().unwrap();
+    assert_send(&pool.get());
+}
+
+#[tokio::test]
+async fn spawn() {
+    let pool = WrapperPool::builder(Manager {})
+        .max_size(1)
+        .build()
+        .unwrap();
+    let wrapper = pool.get().await.unwrap();
+    tokio::spawn(async move {
+        wrapper.obj.set(42);
+    })
+    .await
+    .unwrap();
+    let pool2 = pool.clone();
+    let value = tokio::spawn(async move { pool2.get().await.unwrap().obj.get() })
+        .await
+        .unwrap();
+    assert_eq!(value, 42);
+}
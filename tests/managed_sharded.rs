@@ -0,0 +1,82 @@
+#![cfg(feature = "sharded")]
+
+use std::convert::Infallible;
+
+use deadpool::managed::{
+    self,
+    sharded::{ShardSelection, ShardedPool},
+    Metrics, RecycleResult,
+};
+
+/// Manager creating objects tagged with the id of its shard.
+struct Manager {
+    shard: usize,
+}
+
+impl managed::Manager for Manager {
+    type Type = usize;
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<usize, Infallible> {
+        Ok(self.shard)
+    }
+
+    async fn recycle(&self, _: &mut usize, _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+fn create_pool(max_sizes: &[usize], selection: ShardSelection) -> ShardedPool<Manager> {
+    let shards = max_sizes
+        .iter()
+        .enumerate()
+        .map(|(shard, &max_size)| {
+            managed::Pool::builder(Manager { shard })
+                .max_size(max_size)
+                .build()
+                .unwrap()
+        })
+        .collect();
+    ShardedPool::new(shards, selection)
+}
+
+#[tokio::test]
+async fn round_robin() {
+    let pool = create_pool(&[4, 4, 4], ShardSelection::RoundRobin);
+    let mut shards = Vec::new();
+    for _ in 0..6 {
+        shards.push(*pool.get().await.unwrap());
+    }
+    assert_eq!(shards, vec![0, 1, 2, 0, 1, 2]);
+}
+
+#[tokio::test]
+async fn least_loaded() {
+    let pool = create_pool(&[2, 4, 2], ShardSelection::LeastLoaded);
+    let mut objs = Vec::new();
+    for _ in 0..8 {
+        objs.push(pool.get().await.unwrap());
+    }
+    let mut counts = [0; 3];
+    for obj in &objs {
+        counts[**obj] += 1;
+    }
+    assert_eq!(counts, [2, 4, 2]);
+    let status = pool.status();
+    assert_eq!(status.max_size, 8);
+    assert_eq!(status.size, 8);
+    assert_eq!(status.available, 0);
+    // Objects returned to a shard make it the least loaded one.
+    objs.retain(|obj| **obj != 2);
+    let obj = pool.get().await.unwrap();
+    assert_eq!(*obj, 2);
+    assert_eq!(pool.status().available, 1);
+}
+
+#[tokio::test]
+async fn close() {
+    let pool = create_pool(&[1, 1], ShardSelection::LeastLoaded);
+    pool.close();
+    assert!(pool.shards().iter().all(managed::Pool::is_closed));
+    assert!(pool.get().await.is_err());
+}
@@ -57,6 +57,8 @@ async fn test_managed_timeout(runtime: Runtime) {
         .unwrap();
 
     assert!(matches!(pool.get().await, Err(PoolError::Timeout(_))));
+    let counts = pool.timeout_counts();
+    assert_eq!(counts.wait + counts.create + counts.recycle, 1);
 }
 
 #[cfg(feature = "rt_tokio_1")]
@@ -0,0 +1,94 @@
+#![cfg(all(feature = "managed", feature = "tracing"))]
+
+use std::{
+    convert::Infallible,
+    sync::{Arc, Mutex},
+};
+
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+use deadpool::managed::{self, Metrics, RecycleResult};
+
+struct Manager {}
+
+impl managed::Manager for Manager {
+    type Type = ();
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+/// Subscriber recording the messages of all `deadpool` events.
+#[derive(Clone, Default)]
+struct Recorder {
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+struct MessageVisitor<'a>(&'a mut String);
+
+impl Visit for MessageVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            *self.0 = format!("{:?}", value);
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "deadpool"
+    }
+    fn new_span(&self, _: &span::Attributes<'_>) -> span::Id {
+        span::Id::from_u64(1)
+    }
+    fn record(&self, _: &span::Id, _: &span::Record<'_>) {}
+    fn record_follows_from(&self, _: &span::Id, _: &span::Id) {}
+    fn event(&self, event: &Event<'_>) {
+        let mut message = String::new();
+        event.record(&mut MessageVisitor(&mut message));
+        self.messages.lock().unwrap().push(message);
+    }
+    fn enter(&self, _: &span::Id) {}
+    fn exit(&self, _: &span::Id) {}
+}
+
+#[tokio::test]
+async fn events() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let pool = managed::Pool::<Manager>::builder(Manager {})
+        .max_size(1)
+        .build()
+        .unwrap();
+    drop(pool.get().await.unwrap());
+    drop(pool.get().await.unwrap());
+    let _obj = pool.get().await.unwrap();
+    assert!(pool.try_get().await.is_err());
+
+    let messages = recorder.messages.lock().unwrap();
+    assert_eq!(
+        *messages,
+        [
+            "Slot acquired",
+            "Object created",
+            "Object acquired",
+            "Slot acquired",
+            "Object recycled",
+            "Object acquired",
+            "Slot acquired",
+            "Object recycled",
+            "Object acquired",
+            "Timeout",
+        ]
+    );
+}
@@ -7,7 +7,7 @@ use std::{
 
 use tokio::time;
 
-use deadpool::managed::{self, Metrics, RecycleError, RecycleResult};
+use deadpool::managed::{self, Metrics, PoolError, RecycleError, RecycleResult};
 
 type Pool = managed::Pool<Manager>;
 
@@ -98,3 +98,92 @@ async fn recycle() {
     assert_eq!(status.available, 1);
     assert_eq!(status.size, 1);
 }
+
+#[tokio::test]
+async fn recycle_now() {
+    let manager = Manager {
+        create_fail: false,
+        recycle_fail: true,
+        detached: AtomicUsize::new(0),
+    };
+
+    let pool = Pool::builder(manager).max_size(16).build().unwrap();
+    let mut obj = pool.get().await.unwrap();
+    assert!(matches!(
+        managed::Object::recycle_now(&mut obj).await,
+        Err(RecycleError::Backend(()))
+    ));
+    drop(obj);
+
+    let status = pool.status();
+    assert_eq!(status.available, 0);
+    assert_eq!(status.size, 0);
+    assert_eq!(pool.manager().detached.load(Ordering::Relaxed), 1);
+}
+
+/// Manager which creates a single object that fails to recycle.
+struct RecycleFailManager {
+    created: AtomicUsize,
+}
+
+impl managed::Manager for RecycleFailManager {
+    type Type = ();
+    type Error = &'static str;
+
+    async fn create(&self) -> Result<(), &'static str> {
+        if self.created.fetch_add(1, Ordering::Relaxed) == 0 {
+            Ok(())
+        } else {
+            Err("create failed")
+        }
+    }
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<&'static str> {
+        Err(RecycleError::Backend("recycle failed"))
+    }
+}
+
+#[tokio::test]
+async fn backend_error_originates_from_create() {
+    let pool = managed::Pool::<RecycleFailManager>::builder(RecycleFailManager {
+        created: AtomicUsize::new(0),
+    })
+    .max_size(1)
+    .build()
+    .unwrap();
+    drop(pool.get().await.unwrap());
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::Backend("create failed"))
+    ));
+    assert_eq!(pool.manager().created.load(Ordering::Relaxed), 2);
+}
+
+struct PanicManager {}
+
+impl managed::Manager for PanicManager {
+    type Type = ();
+    type Error = ();
+
+    async fn create(&self) -> Result<(), ()> {
+        panic!("create failed");
+    }
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<()> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn create_panic() {
+    let pool = managed::Pool::<PanicManager>::builder(PanicManager {})
+        .max_size(1)
+        .build()
+        .unwrap();
+    assert!(matches!(
+        pool.get().await,
+        Err(PoolError::CreatePanic(msg)) if msg == "create failed"
+    ));
+    let status = pool.status();
+    assert_eq!(status.available, 0);
+    assert_eq!(status.size, 0);
+    assert_eq!(status.waiting, 0);
+}
@@ -0,0 +1,147 @@
+#![cfg(feature = "managed")]
+
+use std::{
+    convert::Infallible,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use deadpool::managed::{self, Metrics, PoolError, RecycleError, RecycleResult, WaiterMode};
+
+type Pool = managed::Pool<Manager>;
+
+struct Manager {}
+
+impl managed::Manager for Manager {
+    type Type = ();
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<Infallible> {
+        Ok(())
+    }
+}
+
+async fn serving_order(waiter_mode: WaiterMode) -> Vec<usize> {
+    let pool = Pool::builder(Manager {})
+        .max_size(1)
+        .waiter_mode(waiter_mode)
+        .build()
+        .unwrap();
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let obj = pool.get().await.unwrap();
+    let join_handles = (0..3)
+        .map(|i| {
+            let pool = pool.clone();
+            let order = order.clone();
+            tokio::spawn(async move {
+                let _obj = pool.get().await.unwrap();
+                order.lock().unwrap().push(i);
+            })
+        })
+        .collect::<Vec<_>>();
+    // Let the tasks start waiting one after another
+    for i in 0..3 {
+        while pool.status().waiting <= i {
+            tokio::task::yield_now().await;
+        }
+    }
+    drop(obj);
+    for join_handle in join_handles {
+        join_handle.await.unwrap();
+    }
+
+    let order = order.lock().unwrap();
+    order.clone()
+}
+
+#[tokio::test]
+async fn fifo() {
+    assert_eq!(serving_order(WaiterMode::Fifo).await, vec![0, 1, 2]);
+}
+
+#[tokio::test]
+async fn lifo() {
+    assert_eq!(serving_order(WaiterMode::Lifo).await, vec![2, 1, 0]);
+}
+
+/// Manager failing every other recycle.
+#[derive(Default)]
+struct FlakyManager {
+    recycled: AtomicUsize,
+}
+
+impl managed::Manager for FlakyManager {
+    type Type = ();
+    type Error = Infallible;
+
+    async fn create(&self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    async fn recycle(&self, _conn: &mut (), _: &Metrics) -> RecycleResult<Infallible> {
+        if self.recycled.fetch_add(1, Ordering::Relaxed) % 2 == 0 {
+            Err(RecycleError::message("flaky"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[tokio::test]
+async fn fifo_many_waiters() {
+    const WAITERS: usize = 32;
+    let pool = managed::Pool::<FlakyManager>::builder(FlakyManager::default())
+        .max_size(1)
+        .build()
+        .unwrap();
+    let order = Arc::new(Mutex::new(Vec::new()));
+
+    let obj = pool.get().await.unwrap();
+    let mut join_handles = Vec::new();
+    for i in 0..WAITERS {
+        let handle = pool.clone();
+        let order = order.clone();
+        join_handles.push(tokio::spawn(async move {
+            let _obj = handle.get().await.unwrap();
+            order.lock().unwrap().push(i);
+            tokio::task::yield_now().await;
+        }));
+        while pool.status().waiting <= i {
+            tokio::task::yield_now().await;
+        }
+    }
+    drop(obj);
+    for join_handle in join_handles {
+        join_handle.await.unwrap();
+    }
+
+    let order = order.lock().unwrap();
+    assert_eq!(*order, (0..WAITERS).collect::<Vec<_>>());
+}
+
+#[tokio::test]
+async fn lifo_close() {
+    let pool = Pool::builder(Manager {})
+        .max_size(1)
+        .waiter_mode(WaiterMode::Lifo)
+        .build()
+        .unwrap();
+    let _obj = pool.get().await.unwrap();
+    let join_handle = {
+        let pool = pool.clone();
+        tokio::spawn(async move { pool.get().await })
+    };
+    while pool.status().waiting == 0 {
+        tokio::task::yield_now().await;
+    }
+    pool.close();
+    assert!(matches!(join_handle.await.unwrap(), Err(PoolError::Closed)));
+    assert!(matches!(pool.try_get().await, Err(PoolError::Closed)));
+}
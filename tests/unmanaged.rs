@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use tokio::{task, time};
 
-use deadpool::unmanaged::{Pool, PoolError};
+use deadpool::unmanaged::{Object, Pool, PoolError};
 
 #[tokio::test]
 async fn basic() {
@@ -177,3 +177,108 @@ async fn add_timeout() {
 
     assert_eq!(pool.try_remove().unwrap(), 2);
 }
+
+#[tokio::test]
+async fn resize_grow_while_waiting() {
+    let pool = Pool::from(vec![1]);
+    let add = {
+        let pool = pool.clone();
+        tokio::spawn(async move { pool.add(2).await.unwrap() })
+    };
+    task::yield_now().await;
+    assert!(!add.is_finished());
+
+    pool.resize(2);
+    assert!(
+        time::timeout(Duration::from_millis(10), add).await.is_ok(),
+        "add should not timeout"
+    );
+    let status = pool.status();
+    assert_eq!(status.max_size, 2);
+    assert_eq!(status.size, 2);
+    assert_eq!(status.available, 2);
+}
+
+#[tokio::test]
+async fn resize_shrink() {
+    let pool = Pool::from(vec![1, 2, 3]);
+    let obj = pool.get().await.unwrap();
+    pool.resize(1);
+    let status = pool.status();
+    assert_eq!(status.max_size, 1);
+    assert_eq!(status.size, 1);
+    assert_eq!(status.available, 0);
+
+    pool.resize(0);
+    assert_eq!(pool.status().size, 1);
+    assert!(matches!(pool.try_add(4), Err((4, PoolError::Timeout))));
+    let _ = Object::take(obj);
+    assert_eq!(pool.status().size, 0);
+    assert!(matches!(pool.try_add(4), Err((4, PoolError::Timeout))));
+
+    pool.resize(1);
+    assert!(pool.try_add(4).is_ok());
+    assert!(matches!(pool.try_add(5), Err((5, PoolError::Timeout))));
+}
+
+#[tokio::test]
+async fn retain() {
+    let pool = Pool::new(5);
+    for i in 0..5 {
+        pool.try_add(i).unwrap();
+    }
+    let obj = pool.get().await.unwrap();
+    assert_eq!(*obj, 4);
+    pool.retain(|&i| i % 2 == 0);
+    let status = pool.status();
+    assert_eq!(status.size, 3);
+    assert_eq!(status.available, 2);
+
+    // The removed objects free up room for new ones.
+    assert!(pool.try_add(5).is_ok());
+    assert!(pool.try_add(6).is_ok());
+    assert!(matches!(pool.try_add(7), Err((7, PoolError::Timeout))));
+    drop(obj);
+    let mut values = (0..5)
+        .map(|_| pool.try_remove().unwrap())
+        .collect::<Vec<_>>();
+    values.sort_unstable();
+    assert_eq!(values, [0, 2, 4, 5, 6]);
+}
+
+#[tokio::test]
+async fn waiting() {
+    let pool = Pool::from(vec![1]);
+    let obj = pool.get().await.unwrap();
+    let getters = (0..3)
+        .map(|_| {
+            let pool = pool.clone();
+            tokio::spawn(async move { drop(pool.get().await.unwrap()) })
+        })
+        .collect::<Vec<_>>();
+    task::yield_now().await;
+    let status = pool.status();
+    assert_eq!(status.available, 0);
+    assert_eq!(status.waiting, 3);
+
+    drop(obj);
+    for getter in getters {
+        getter.await.unwrap();
+    }
+    let status = pool.status();
+    assert_eq!(status.available, 1);
+    assert_eq!(status.waiting, 0);
+}
+
+#[tokio::test]
+async fn waiting_timeout() {
+    let pool = Pool::from(vec![1]);
+    let _obj = pool.get().await.unwrap();
+    assert!(matches!(
+        pool.timeout_get(Some(Duration::ZERO)).await,
+        Err(PoolError::Timeout)
+    ));
+    let status = pool.status();
+    assert_eq!(status.available, 0);
+    assert_eq!(status.waiting, 0);
+}